@@ -0,0 +1,26 @@
+use anyhow::Result;
+
+use crate::lines;
+
+//##################################################################################################
+// Parsing & Data Prep & Puzzle Logic
+//##################################################################################################
+
+fn parse(input: &str) -> Result<&str> {
+    // TODO: implement real logic here
+    Ok(input)
+}
+
+//##################################################################################################
+// Solutions
+//##################################################################################################
+
+pub fn part1(input: &str) -> Result<i64> {
+    // TODO: implement real logic here
+    Ok(lines(parse(input)?).count() as i64)
+}
+
+pub fn part2(input: &str) -> Result<i64> {
+    // TODO: implement real logic here
+    Ok(input.lines().map(|l| l.len() as i64).sum())
+}
@@ -20,27 +20,34 @@ DEFAULT_YEAR = 2025
 DEFAULT_DELAY = 1.0
 TEMPLATE_FILE = Path("AOC_TEMPLATE.py")
 RUST_TEMPLATE_FILE = Path("AOC_TEMPLATE.rs")
+RUST_SOLUTION_TEMPLATE_FILE = Path("AOC_SOLUTION_TEMPLATE.rs")
+SOLUTIONS_DIR = Path("src/solutions")
 DEFAULT_USER_AGENT = os.environ.get(
     "AOC_USER_AGENT",
     "github.com/your-handle/AdventOfCode_2025 (please set AOC_USER_AGENT with contact info)",
 )
 
 ##################################################################################################
-# Rust fallback template
+# Rust fallback templates
 ##################################################################################################
 
 RUST_FALLBACK = """\
-use anyhow::{anyhow, bail, Result};
-use aoc2025::{confirm_prompt, detect_part, get_input, lines, load_example, submit_answer, time, DEFAULT_YEAR};
+fn main() -> anyhow::Result<()> {
+    aoc2025::run_cli(Some({{DAY}}))
+}
+"""
+
+RUST_SOLUTION_FALLBACK = """\
+use anyhow::Result;
 
-const DAY: u8 = {{DAY}};
+use crate::lines;
 
 //##################################################################################################
 // Parsing & Data Prep & Puzzle Logic
 //##################################################################################################
 
 fn parse(input: &str) -> Result<&str> {
-    // TODO: implement real logic
+    // TODO: implement real logic here
     Ok(input)
 }
 
@@ -48,115 +55,15 @@ fn parse(input: &str) -> Result<&str> {
 // Solutions
 //##################################################################################################
 
-fn part1(input: &str) -> Result<i64> {
-    // TODO: implement real logic
-    Ok(lines(input).count() as i64)
+pub fn part1(input: &str) -> Result<i64> {
+    // TODO: implement real logic here
+    Ok(lines(parse(input)?).count() as i64)
 }
 
-fn part2(input: &str) -> Result<i64> {
-    // TODO: implement real logic
+pub fn part2(input: &str) -> Result<i64> {
+    // TODO: implement real logic here
     Ok(input.lines().map(|l| l.len() as i64).sum())
 }
-
-//##################################################################################################
-// CLI Arguments
-//##################################################################################################
-
-#[derive(Debug, Default)]
-struct Args {
-    part: Option<u8>,
-    year: i32,
-    example: bool,
-    submit: bool,
-    no_confirm: bool,
-}
-
-fn parse_args() -> Result<Args> {
-    let mut args = Args {
-        year: DEFAULT_YEAR,
-        ..Default::default()
-    };
-
-    let mut iter = std::env::args().skip(1);
-    while let Some(arg) = iter.next() {
-        match arg.as_str() {
-            "--part" => {
-                let val = iter
-                    .next()
-                    .ok_or_else(|| anyhow!("--part requires a value"))?;
-                args.part = Some(val.parse()?);
-            }
-            "--year" => {
-                let val = iter
-                    .next()
-                    .ok_or_else(|| anyhow!("--year requires a value"))?;
-                args.year = val.parse()?;
-            }
-            "--example" => args.example = true,
-            "--submit" => args.submit = true,
-            "--no-confirm" => args.no_confirm = true,
-            "--help" | "-h" => {
-                print_usage();
-                std::process::exit(0);
-            }
-            other => bail!("Unknown argument: {other}"),
-        }
-    }
-
-    Ok(args)
-}
-
-fn print_usage() {
-    eprintln!(
-        "\
-Day {day} runner
-  --part <1|2>     Force part (default: detect instructions-two.md)
-  --year <YYYY>    Override year (default: {})
-  --example        Use Example_{day_pad}.txt if present
-  --submit         Submit the computed answer
-  --no-confirm     Skip prompt when submitting
-",
-        DEFAULT_YEAR
-    );
-}
-
-//##################################################################################################
-// Entry Point
-//##################################################################################################
-
-fn main() -> Result<()> {
-    let args = parse_args()?;
-    let part = args.part.unwrap_or_else(|| detect_part(DAY));
-
-    let raw = if args.example {
-        load_example(DAY)?
-    } else {
-        get_input(DAY, args.year)?
-    };
-
-    let (ans1, t1) = time(|| part1(&raw).unwrap());
-    println!("Part 1: {ans1} ({t1} ms)");
-
-    let (ans2, t2) = time(|| part2(&raw).unwrap());
-    println!("Part 2: {ans2} ({t2} ms)");
-
-    if args.submit {
-        let answer = match part {
-            1 => ans1,
-            2 => ans2,
-            _ => bail!("Part must be 1 or 2"),
-        };
-
-        if !args.no_confirm {
-            confirm_prompt()?;
-        }
-
-        let verdict = submit_answer(DAY, part, answer, args.year)?;
-        println!("Submission verdict: {verdict}");
-    }
-
-    Ok(())
-}
 """
 
 
@@ -263,18 +170,56 @@ def copy_python_template(day: int, day_dir: Path, force_template: bool) -> None:
         )
 
 
+def render_rust_template(template: Path, fallback: str, day: int) -> str:
+    contents = template.read_text() if template.exists() else fallback
+    return contents.replace("{{DAY}}", str(day)).replace("{{DAY_PAD}}", f"{day:02d}")
+
+
 def scaffold_rust_bin(day: int, day_dir: Path, cargo_toml: Path, rust_template: Path):
     bin_path = day_dir / f"day{day:02d}.rs"
     if not bin_path.exists():
-        contents = (
-            rust_template.read_text() if rust_template.exists() else RUST_FALLBACK
-        )
-        contents = contents.replace("{{DAY}}", str(day)).replace(
-            "{{DAY_PAD}}", f"{day:02d}"
-        )
-        bin_path.write_text(contents)
+        bin_path.write_text(render_rust_template(rust_template, RUST_FALLBACK, day))
         logger.info(f"Created Rust bin {bin_path}")
     register_bin_in_cargo(day, cargo_toml)
+    scaffold_rust_solution(day)
+
+
+def scaffold_rust_solution(day: int) -> None:
+    """Create src/solutions/dayXX.rs and register it in the solutions registry."""
+
+    name = f"day{day:02d}"
+    solution_path = SOLUTIONS_DIR / f"{name}.rs"
+    if not solution_path.exists():
+        SOLUTIONS_DIR.mkdir(parents=True, exist_ok=True)
+        solution_path.write_text(
+            render_rust_template(
+                RUST_SOLUTION_TEMPLATE_FILE, RUST_SOLUTION_FALLBACK, day
+            )
+        )
+        logger.info(f"Created Rust solution {solution_path}")
+    register_solution_module(day)
+
+
+def register_solution_module(day: int) -> None:
+    name = f"day{day:02d}"
+    mod_rs = SOLUTIONS_DIR / "mod.rs"
+    if not mod_rs.exists():
+        logger.warning(f"{mod_rs} not found; cannot register solution {name}")
+        return
+
+    text = mod_rs.read_text()
+    if re.search(rf"^pub mod {name};", text, re.MULTILINE):
+        return
+
+    mods = list(re.finditer(r"^pub mod day\d+;\n", text, re.MULTILINE))
+    insert_at = mods[-1].end() if mods else 0
+    text = text[:insert_at] + f"pub mod {name};\n" + text[insert_at:]
+
+    entry = f"    ({day}, {name}::part1, {name}::part2),\n"
+    close = text.rindex("];")
+    text = text[:close] + entry + text[close:]
+    mod_rs.write_text(text)
+    logger.info(f"Registered solution {name} in {mod_rs}")
 
 
 def register_bin_in_cargo(day: int, cargo_toml: Path) -> None:
@@ -0,0 +1,215 @@
+use anyhow::{anyhow, bail, Result};
+use aoc2025::solutions::{parse_day_list, select_days, solvers_for};
+use aoc2025::{
+    append_profile, default_year, fmt_duration, get_input, load_example, time_result_duration,
+};
+use std::env;
+use std::path::PathBuf;
+use std::time::Duration;
+
+//##################################################################################################
+// CLI Arguments
+//##################################################################################################
+
+#[derive(Debug, Default)]
+struct Args {
+    only: Option<Vec<u8>>,
+    since: Option<u8>,
+    part: Option<u8>,
+    year: i32,
+    example: bool,
+    profile: Option<PathBuf>,
+    #[cfg(feature = "rayon")]
+    bench: bool,
+}
+
+fn parse_args() -> Result<Args> {
+    let mut args = Args {
+        year: default_year(),
+        ..Default::default()
+    };
+
+    let mut iter = env::args().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--only" => {
+                let val = iter
+                    .next()
+                    .ok_or_else(|| anyhow!("--only requires a value"))?;
+                args.only = Some(parse_day_list(&val)?);
+            }
+            "--since" => {
+                let val = iter
+                    .next()
+                    .ok_or_else(|| anyhow!("--since requires a value"))?;
+                let day: u8 = val.parse()?;
+                if !(1..=25).contains(&day) {
+                    bail!("--since must be between 1 and 25");
+                }
+                args.since = Some(day);
+            }
+            "--part" => {
+                let val = iter
+                    .next()
+                    .ok_or_else(|| anyhow!("--part requires a value"))?;
+                let part: u8 = val.parse()?;
+                if !(1..=2).contains(&part) {
+                    bail!("Part must be 1 or 2");
+                }
+                args.part = Some(part);
+            }
+            "--year" => {
+                let val = iter
+                    .next()
+                    .ok_or_else(|| anyhow!("--year requires a value"))?;
+                args.year = val.parse()?;
+            }
+            "--profile" => {
+                let val = iter
+                    .next()
+                    .ok_or_else(|| anyhow!("--profile requires a value"))?;
+                args.profile = Some(PathBuf::from(val));
+            }
+            "--example" => args.example = true,
+            #[cfg(feature = "rayon")]
+            "--bench" => args.bench = true,
+            #[cfg(not(feature = "rayon"))]
+            "--bench" => {
+                bail!("--bench needs the rayon feature (cargo run --features rayon --bin all)")
+            }
+            "--help" | "-h" => {
+                print_usage();
+                std::process::exit(0);
+            }
+            other => bail!("Unknown argument: {other}"),
+        }
+    }
+
+    Ok(args)
+}
+
+fn print_usage() {
+    eprintln!(
+        "\
+All-days runner
+  --only <1,3,5>   Run only these days (must be registered)
+  --since <day>    Run days >= day
+  --part <1|2>     Run only this part (default: both)
+  --year <YYYY>    Override year (default: {})
+  --example        Use Example_XX.txt instead of the real input
+  --profile <CSV>  Append day,part,ms,timestamp rows to CSV
+  --bench          Solve the selected days/parts in parallel and compare wall vs summed time
+                   (needs --features rayon)
+",
+        default_year()
+    );
+}
+
+//##################################################################################################
+// Entry Point
+//##################################################################################################
+
+fn main() -> Result<()> {
+    let args = parse_args()?;
+    let days = select_days(args.only.as_deref(), args.since);
+    if days.is_empty() {
+        bail!("No registered days match the selection");
+    }
+
+    #[cfg(feature = "rayon")]
+    if args.bench {
+        return run_bench(&args, &days);
+    }
+
+    let mut total = Duration::ZERO;
+    for day in days {
+        let Some((part1, part2)) = solvers_for(day) else {
+            continue;
+        };
+        let raw = if args.example {
+            load_example(day)?
+        } else {
+            get_input(day, args.year)?
+        };
+
+        for (part, solver) in [(1, part1), (2, part2)] {
+            if args.part.map_or(false, |p| p != part) {
+                continue;
+            }
+            let (ans, elapsed) = time_result_duration(|| solver(&raw))?;
+            total += elapsed;
+            if let Some(path) = &args.profile {
+                append_profile(path, day, part, elapsed.as_millis())?;
+            }
+            println!(
+                "Day {day:02} Part {part}: {ans} ({})",
+                fmt_duration(elapsed)
+            );
+        }
+    }
+    println!("Total: {}", fmt_duration(total));
+
+    Ok(())
+}
+
+//##################################################################################################
+// Parallel Bench
+//##################################################################################################
+
+/// Load every selected input up front (fetches stay sequential), then solve all `(day, part)`
+/// jobs at once on the rayon pool. Per-part timings are printed in day order, followed by the
+/// wall-clock time and the sum of the individual timings.
+#[cfg(feature = "rayon")]
+fn run_bench(args: &Args, days: &[u8]) -> Result<()> {
+    use rayon::prelude::*;
+    use std::time::Instant;
+
+    let mut jobs = Vec::new();
+    for &day in days {
+        let Some((part1, part2)) = solvers_for(day) else {
+            continue;
+        };
+        let raw = if args.example {
+            load_example(day)?
+        } else {
+            get_input(day, args.year)?
+        };
+        for (part, solver) in [(1, part1), (2, part2)] {
+            if args.part.map_or(false, |p| p != part) {
+                continue;
+            }
+            jobs.push((day, part, solver, raw.clone()));
+        }
+    }
+
+    let start = Instant::now();
+    let results = jobs
+        .par_iter()
+        .map(|(day, part, solver, raw)| {
+            let (ans, elapsed) = time_result_duration(|| solver(raw))
+                .map_err(|e| anyhow!("Day {day:02} Part {part}: {e}"))?;
+            Ok((*day, *part, ans, elapsed))
+        })
+        .collect::<Result<Vec<_>>>()?;
+    let wall = start.elapsed();
+
+    let mut total = Duration::ZERO;
+    for (day, part, ans, elapsed) in results {
+        total += elapsed;
+        if let Some(path) = &args.profile {
+            append_profile(path, day, part, elapsed.as_millis())?;
+        }
+        println!(
+            "Day {day:02} Part {part}: {ans} ({})",
+            fmt_duration(elapsed)
+        );
+    }
+    println!(
+        "Wall: {} (sum of parts: {}, {} threads)",
+        fmt_duration(wall),
+        fmt_duration(total),
+        rayon::current_num_threads()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,3 @@
+fn main() -> anyhow::Result<()> {
+    aoc2025::run_cli(None)
+}
@@ -0,0 +1,68 @@
+use anyhow::{anyhow, Result};
+
+//##################################################################################################
+// MD5 Helpers (feature = "hashing")
+//##################################################################################################
+
+/// Lowercase hex MD5 digest of `input`.
+pub fn md5_hex(input: &str) -> String {
+    format!("{:x}", md5::compute(input))
+}
+
+/// Whether the digest's hex form starts with `zeros` zero nibbles (checked on raw bytes).
+pub fn has_zero_prefix(digest: &[u8; 16], zeros: usize) -> bool {
+    let full = zeros / 2;
+    if full > digest.len() || digest[..full].iter().any(|&b| b != 0) {
+        return false;
+    }
+    zeros % 2 == 0 || digest.get(full).map_or(false, |&b| b < 0x10)
+}
+
+/// Most leading zero nibbles a digest can have (an MD5 hex digest is 32 characters).
+pub const MAX_ZERO_PREFIX: usize = 32;
+
+/// Lowest `n >= 0` such that `md5(salt + n)` starts with `zeros` hex zeros. Errors when `zeros`
+/// exceeds `MAX_ZERO_PREFIX` or no `n` in the `u64` range matches.
+pub fn find_hash_with_prefix(salt: &str, zeros: usize) -> Result<u64> {
+    check_zero_prefix(zeros)?;
+    let mut base = md5::Context::new();
+    base.consume(salt);
+
+    (0..=u64::MAX)
+        .find(|n| {
+            let mut ctx = base.clone();
+            ctx.consume(n.to_string());
+            has_zero_prefix(&ctx.finalize(), zeros)
+        })
+        .ok_or_else(|| anyhow!("No u64 suffix gives {zeros} leading zeros for '{salt}'"))
+}
+
+/// Parallel `find_hash_with_prefix`: scans fixed-size chunks with rayon, keeping the lowest match.
+#[cfg(feature = "rayon")]
+pub fn par_find_hash_with_prefix(salt: &str, zeros: usize) -> Result<u64> {
+    const CHUNK: u64 = 1 << 16;
+
+    check_zero_prefix(zeros)?;
+    let mut base = md5::Context::new();
+    base.consume(salt);
+
+    (0..u64::MAX / CHUNK)
+        .find_map(|chunk| {
+            let start = chunk * CHUNK;
+            crate::par_find(start..start + CHUNK, |n| {
+                let mut ctx = base.clone();
+                ctx.consume(n.to_string());
+                has_zero_prefix(&ctx.finalize(), zeros)
+            })
+        })
+        .ok_or_else(|| anyhow!("No u64 suffix gives {zeros} leading zeros for '{salt}'"))
+}
+
+fn check_zero_prefix(zeros: usize) -> Result<()> {
+    if zeros > MAX_ZERO_PREFIX {
+        return Err(anyhow!(
+            "An MD5 hex digest has only {MAX_ZERO_PREFIX} nibbles; cannot match {zeros} zeros"
+        ));
+    }
+    Ok(())
+}
@@ -1,11 +1,18 @@
-use std::collections::{HashMap, VecDeque};
+use std::collections::{HashMap, HashSet, VecDeque};
 use std::fs;
 use std::io::{self, Write};
-use std::path::PathBuf;
+use std::path::{Path, PathBuf};
 
 use anyhow::{anyhow, Context, Result};
 use reqwest::blocking::Client;
 
+pub mod solutions;
+pub mod tape;
+pub mod vm;
+
+#[cfg(feature = "hashing")]
+pub mod hashing;
+
 pub const DEFAULT_YEAR: i32 = 2025;
 const USER_AGENT_FALLBACK: &str =
     "github.com/your-handle/AdventOfCode_2025 (please set AOC_USER_AGENT with contact info)";
@@ -14,9 +21,30 @@ const USER_AGENT_FALLBACK: &str =
 // Input Fetching & Caching
 //##################################################################################################
 
-/// Load the puzzle input for the given day. If not cached locally, fetch from AoC and cache.
+/// Load the puzzle input for the given day (year from `default_year`). If not cached locally,
+/// fetch from AoC and cache.
 pub fn read_input(day: u8) -> Result<String> {
-    get_input(day, DEFAULT_YEAR)
+    get_input(day, default_year())
+}
+
+/// Types that can be built straight from a day's raw input (see `read_input_as`).
+pub trait FromInput: Sized {
+    fn from_input(input: &str) -> Result<Self>;
+}
+
+/// `read_input` followed by `T::from_input`.
+pub fn read_input_as<T: FromInput>(day: u8) -> Result<T> {
+    T::from_input(&read_input(day)?)
+}
+
+/// Input split into lines (`\r\n` tolerated, inner blank lines kept, no trailing empty line).
+pub fn read_lines(day: u8) -> Result<Vec<String>> {
+    Ok(read_input(day)?.lines().map(str::to_string).collect())
+}
+
+/// Every integer in the input, in order (see `ints`).
+pub fn read_ints(day: u8) -> Result<Vec<i64>> {
+    Ok(ints(&read_input(day)?))
 }
 
 /// Fetch (or read cached) puzzle input for a given day/year.
@@ -77,23 +105,410 @@ fn input_paths(day: u8) -> Vec<PathBuf> {
 // Parsing Helpers
 //##################################################################################################
 
+/// Parse failure located in the input: 1-based `line` and byte `column`.
+/// Wrapped in `anyhow::Error` by the parsers; `downcast_ref::<ParseError>()` recovers it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub message: String,
+}
+
+impl ParseError {
+    pub fn new(line: usize, column: usize, message: impl Into<String>) -> Self {
+        Self {
+            line,
+            column,
+            message: message.into(),
+        }
+    }
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "line {}: {} (column {})",
+            self.line, self.message, self.column
+        )
+    }
+}
+
+impl std::error::Error for ParseError {}
+
 /// Split input into trimmed lines (keeps empty lines if present).
 pub fn lines(input: &str) -> impl Iterator<Item = &str> {
     input.split('\n').map(|s| s.trim_end_matches('\r'))
 }
 
+/// Parse every non-blank line (trimmed) as `T`, collecting failures instead of stopping at the
+/// first: returns the parsed values plus `(line_number, line)` for each bad line (1-based).
+pub fn parse_lines_collect<T: std::str::FromStr>(input: &str) -> (Vec<T>, Vec<(usize, String)>) {
+    let (mut values, mut errors) = (Vec::new(), Vec::new());
+    for (i, line) in lines(input).enumerate() {
+        let line = line.trim();
+        if line.is_empty() {
+            continue;
+        }
+        match line.parse::<T>() {
+            Ok(v) => values.push(v),
+            Err(_) => errors.push((i + 1, line.to_string())),
+        }
+    }
+    (values, errors)
+}
+
 /// Parse a whitespace-separated grid of integers into Vec<Vec<i64>>.
 pub fn parse_int_grid(input: &str) -> Result<Vec<Vec<i64>>> {
+    parse_int_grid_with(input, &[])
+}
+
+/// Like `parse_int_grid`, but `separators` (e.g. `&[',']`) also split tokens; whitespace always does.
+/// Padding never reaches a token and tokens may carry a sign (`-7`, `+7`), so space-padded
+/// fixed-width tables parse.
+/// A bad token fails with a `ParseError` naming its line and column.
+pub fn parse_int_grid_with(input: &str, separators: &[char]) -> Result<Vec<Vec<i64>>> {
     input
         .lines()
-        .map(|line| {
-            line.split_whitespace()
-                .map(|tok| tok.parse::<i64>().map_err(|e| anyhow!(e)))
+        .enumerate()
+        .map(|(i, line)| {
+            line.split(|c: char| c.is_whitespace() || separators.contains(&c))
+                .filter(|tok| !tok.is_empty())
+                .map(|tok| {
+                    // `i64::from_str` takes one optional `+`/`-`, so `+-3` stays an error.
+                    tok.parse::<i64>().map_err(|_| {
+                        let column = tok.as_ptr() as usize - line.as_ptr() as usize + 1;
+                        ParseError::new(i + 1, column, format!("invalid integer '{tok}'")).into()
+                    })
+                })
                 .collect::<Result<Vec<_>>>()
         })
         .collect()
 }
 
+/// `parse_int_grid` for rectangular inputs: returns `(grid, width, height)` and fails with the
+/// offending line if any row's width differs from the first row's.
+pub fn parse_int_grid_rect(input: &str) -> Result<(Vec<Vec<i64>>, usize, usize)> {
+    let grid = parse_int_grid(input)?;
+    let width = grid.first().map_or(0, Vec::len);
+    if let Some((i, row)) = grid.iter().enumerate().find(|(_, row)| row.len() != width) {
+        let line = input.lines().nth(i).unwrap_or("");
+        return Err(anyhow!(ParseError::new(
+            i + 1,
+            1,
+            format!("row has {} values, expected {width}: '{line}'", row.len())
+        )));
+    }
+    let height = grid.len();
+    Ok((grid, width, height))
+}
+
+/// Parse inclusive integer ranges written as `a-b`, `a..=b`, `a..b` (exclusive end) or a bare
+/// `a,b` line. Range tokens may be separated by commas, whitespace or newlines; a line of exactly
+/// two plain integers is read as one `a,b` range. Anything else is an error rather than a guess.
+pub fn parse_ranges_flexible(input: &str) -> Result<Vec<(i64, i64)>> {
+    let mut ranges = Vec::new();
+    for (i, line) in lines(input).enumerate() {
+        let toks: Vec<&str> = line
+            .split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|t| !t.is_empty())
+            .collect();
+        if toks.is_empty() {
+            continue;
+        }
+
+        let pair = (toks.len() == 2)
+            .then(|| Some((toks[0].parse::<i64>().ok()?, toks[1].parse::<i64>().ok()?)))
+            .flatten();
+        match pair {
+            Some((a, b)) if a > b => {
+                return Err(anyhow!("line {}: range start > end: {line}", i + 1));
+            }
+            Some(p) => ranges.push(p),
+            None => {
+                for tok in &toks {
+                    let range = parse_range_token(tok)
+                        .with_context(|| format!("line {}: invalid range line '{line}'", i + 1))?;
+                    ranges.push(range);
+                }
+            }
+        }
+    }
+    Ok(ranges)
+}
+
+/// One `a-b`, `a..=b` or `a..b` token as an inclusive, non-empty range; errors quote the token
+/// as written.
+fn parse_range_token(tok: &str) -> Result<(i64, i64)> {
+    let num = |s: &str| {
+        s.trim()
+            .parse::<i64>()
+            .with_context(|| format!("Invalid range bound '{s}' in '{tok}'"))
+    };
+    let (a, b) = if let Some((a, b)) = tok.split_once("..=") {
+        (num(a)?, num(b)?)
+    } else if let Some((a, b)) = tok.split_once("..") {
+        let a = num(a)?;
+        let end = num(b)?
+            .checked_sub(1)
+            .filter(|&end| end >= a)
+            .ok_or_else(|| anyhow!("Empty range '{tok}' (exclusive end must exceed start)"))?;
+        (a, end)
+    } else if let Some(i) = tok.get(1..).and_then(|rest| rest.find('-')) {
+        // Skip a leading sign so `-5--3` splits at the second dash.
+        (num(&tok[..=i])?, num(&tok[i + 2..])?)
+    } else {
+        return Err(anyhow!("Unrecognized range '{tok}'"));
+    };
+    if a > b {
+        return Err(anyhow!("Range start > end in '{tok}'"));
+    }
+    Ok((a, b))
+}
+
+/// Split input into blank-line separated blocks (trailing newlines stripped from each block).
+pub fn split_blocks(input: &str) -> Vec<&str> {
+    let mut blocks = Vec::new();
+    let mut start = None;
+    let mut offset = 0;
+
+    for raw in input.split_inclusive('\n') {
+        if raw.trim().is_empty() {
+            if let Some(s) = start.take() {
+                blocks.push(input[s..offset].trim_end_matches(['\n', '\r']));
+            }
+        } else if start.is_none() {
+            start = Some(offset);
+        }
+        offset += raw.len();
+    }
+
+    if let Some(s) = start {
+        blocks.push(input[s..].trim_end_matches(['\n', '\r']));
+    }
+    blocks
+}
+
+/// Parse a character grid into Vec<Vec<char>> (empty lines are skipped).
+pub fn parse_char_grid(input: &str) -> Vec<Vec<char>> {
+    lines(input)
+        .filter(|l| !l.is_empty())
+        .map(|l| l.chars().collect())
+        .collect()
+}
+
+/// Parse several blank-line separated char grids (image layers, folded sheets, ...).
+pub fn parse_grids(input: &str) -> Vec<Vec<Vec<char>>> {
+    split_blocks(input)
+        .into_iter()
+        .map(parse_char_grid)
+        .collect()
+}
+
+/// Parse a `[A] [B]` crate diagram into per-column stacks, bottom to top. Crates sit at character
+/// positions 1, 5, 9, ...; the trailing ` 1   2   3` label row (if present) only sizes the result.
+pub fn parse_crate_stacks(block: &str) -> Vec<Vec<char>> {
+    let rows: Vec<&str> = lines(block).filter(|l| !l.trim().is_empty()).collect();
+    let width = rows.iter().map(|r| (r.len() + 3) / 4).max().unwrap_or(0);
+    let mut stacks = vec![Vec::new(); width];
+    for row in rows.iter().rev() {
+        for (i, stack) in stacks.iter_mut().enumerate() {
+            if let Some(c) = row.chars().nth(4 * i + 1).filter(char::is_ascii_alphabetic) {
+                stack.push(c);
+            }
+        }
+    }
+    stacks
+}
+
+/// Parse `move N from A to B` lines into `(count, from, to)` with the puzzle's 1-based stack
+/// numbers kept as written.
+pub fn parse_moves(block: &str) -> Result<Vec<(usize, usize, usize)>> {
+    lines(block)
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(
+            |(i, line)| match line.split_whitespace().collect::<Vec<_>>()[..] {
+                ["move", n, "from", a, "to", b] => {
+                    let num = |tok: &str| {
+                        tok.parse::<usize>().map_err(|_| {
+                            anyhow!(ParseError::new(i + 1, 1, format!("invalid number '{tok}'")))
+                        })
+                    };
+                    Ok((num(n)?, num(a)?, num(b)?))
+                }
+                _ => Err(anyhow!(ParseError::new(
+                    i + 1,
+                    1,
+                    format!("expected 'move N from A to B', got '{line}'")
+                ))),
+            },
+        )
+        .collect()
+}
+
+/// Text after the first `prefix` up to the next `suffix` (or end of line when `suffix` is empty).
+pub fn extract_between<'a>(line: &'a str, prefix: &str, suffix: &str) -> Option<&'a str> {
+    let start = line.find(prefix)? + prefix.len();
+    let rest = &line[start..];
+    if suffix.is_empty() {
+        return Some(rest);
+    }
+    rest.find(suffix).map(|end| &rest[..end])
+}
+
+/// Match `line` against a format `pattern` where each `{}` captures text up to the next literal
+/// piece, e.g. `scan(l, "Sensor at x={}, y={}: closest beacon is at x={}, y={}")`. The literal
+/// text must match exactly (including the tail); adjacent `{}{}` are rejected as ambiguous.
+/// See `scan!` for parsing the captures straight into typed values.
+pub fn scan<'a>(line: &'a str, pattern: &str) -> Result<Vec<&'a str>> {
+    let pieces: Vec<&str> = pattern.split("{}").collect();
+    let mut rest = line
+        .strip_prefix(pieces[0])
+        .ok_or_else(|| anyhow!("'{line}' does not start with '{}'", pieces[0]))?;
+    let mut fields = Vec::with_capacity(pieces.len() - 1);
+    for (i, lit) in pieces.iter().enumerate().skip(1) {
+        let last = i == pieces.len() - 1;
+        let end = if last && lit.is_empty() {
+            rest.len()
+        } else if lit.is_empty() {
+            return Err(anyhow!("Pattern '{pattern}' has adjacent placeholders"));
+        } else if last {
+            rest.strip_suffix(lit)
+                .ok_or_else(|| anyhow!("'{line}' does not end with '{lit}'"))?
+                .len()
+        } else {
+            rest.find(lit)
+                .ok_or_else(|| anyhow!("'{line}' is missing '{lit}' (pattern '{pattern}')"))?
+        };
+        fields.push(&rest[..end]);
+        rest = &rest[end + lit.len()..];
+    }
+    Ok(fields)
+}
+
+/// `scan` that also checks the pattern has exactly `expected` placeholders; used by `scan!`.
+#[doc(hidden)]
+pub fn scan_n<'a>(line: &'a str, pattern: &str, expected: usize) -> Result<Vec<&'a str>> {
+    let fields = scan(line, pattern)?;
+    if fields.len() != expected {
+        return Err(anyhow!(
+            "Pattern '{pattern}' has {} placeholders but {expected} types were given",
+            fields.len()
+        ));
+    }
+    Ok(fields)
+}
+
+/// Parse one `scan!` capture (trimmed) as `T`.
+#[doc(hidden)]
+pub fn scan_field<T>(tok: Option<&str>) -> Result<T>
+where
+    T: std::str::FromStr,
+    T::Err: std::fmt::Display,
+{
+    let tok = tok.ok_or_else(|| anyhow!("Missing scan field"))?.trim();
+    tok.parse::<T>()
+        .map_err(|e| anyhow!("Invalid {} '{tok}': {e}", std::any::type_name::<T>()))
+}
+
+/// Typed `scan`: `scan!(line, "x={}, y={}", i64, i64)` yields `Result<(i64, i64)>`.
+#[macro_export]
+macro_rules! scan {
+    ($line:expr, $pattern:expr, $($ty:ty),+ $(,)?) => {
+        $crate::scan_n($line, $pattern, [$(stringify!($ty)),+].len()).and_then(|fields| {
+            let mut fields = fields.into_iter();
+            Ok(($($crate::scan_field::<$ty>(fields.next())?,)+))
+        })
+    };
+}
+
+/// First two single-character tokens of each non-blank line (`A Y` strategy-guide style).
+/// Extra tokens after the second are ignored; a line with fewer than two tokens, or a token longer
+/// than one character, fails with a `ParseError`.
+pub fn parse_token_pairs(input: &str) -> Result<Vec<(char, char)>> {
+    lines(input)
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty())
+        .map(|(i, line)| {
+            let mut toks = line.split_whitespace().map(|tok| {
+                let mut chars = tok.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => Ok(c),
+                    _ => Err(anyhow!(ParseError::new(
+                        i + 1,
+                        1,
+                        format!("expected a single character, got '{tok}'")
+                    ))),
+                }
+            });
+            match (toks.next(), toks.next()) {
+                (Some(a), Some(b)) => Ok((a?, b?)),
+                _ => Err(anyhow!(ParseError::new(
+                    i + 1,
+                    1,
+                    format!("expected two tokens, got '{line}'")
+                ))),
+            }
+        })
+        .collect()
+}
+
+/// Parse whitespace/newline separated `key:value` (or `key=value`) tokens into a map.
+pub fn parse_kv(block: &str) -> Result<HashMap<String, String>> {
+    block
+        .split_whitespace()
+        .map(|tok| {
+            tok.split_once([':', '='])
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .ok_or_else(|| anyhow!("Expected key:value, got '{tok}'"))
+        })
+        .collect()
+}
+
+/// Parse blank-line separated `key:value` records (passport style).
+pub fn parse_key_value_records(input: &str) -> Result<Vec<Record>> {
+    split_blocks(input)
+        .into_iter()
+        .enumerate()
+        .map(|(i, block)| {
+            parse_kv(block)
+                .map(Record)
+                .with_context(|| format!("record {}", i + 1))
+        })
+        .collect()
+}
+
+/// Key/value record with typed accessors and small validation checks.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Record(pub HashMap<String, String>);
+
+impl Record {
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+
+    /// Field parsed as an integer; `None` if missing or not a number.
+    pub fn get_int(&self, key: &str) -> Option<i64> {
+        self.get(key)?.parse().ok()
+    }
+
+    /// True if every key in `keys` is present.
+    pub fn has_all(&self, keys: &[&str]) -> bool {
+        keys.iter().all(|k| self.0.contains_key(*k))
+    }
+
+    /// True if the field is an integer inside `range`.
+    pub fn int_in(&self, key: &str, range: std::ops::RangeInclusive<i64>) -> bool {
+        self.get_int(key).map_or(false, |v| range.contains(&v))
+    }
+
+    /// True if the field is present and satisfies `pred`.
+    pub fn check(&self, key: &str, pred: impl Fn(&str) -> bool) -> bool {
+        self.get(key).map_or(false, pred)
+    }
+}
+
 //##################################################################################################
 // Timing Helpers
 //##################################################################################################
@@ -114,6 +529,69 @@ pub fn time_result<R, F: FnOnce() -> Result<R>>(f: F) -> Result<(R, u128)> {
     Ok((res, elapsed))
 }
 
+/// Like `time_result`, but keeps the full-precision `Duration` (sub-millisecond parts show up).
+pub fn time_result_duration<R, F: FnOnce() -> Result<R>>(f: F) -> Result<(R, std::time::Duration)> {
+    let start = std::time::Instant::now();
+    let res = f()?;
+    Ok((res, start.elapsed()))
+}
+
+/// Human-readable duration in the largest unit that keeps the value under 1000, with three
+/// significant figures and trailing zeros dropped: `850ns`, `342µs`, `1.2ms`, `3.45s`.
+pub fn fmt_duration(d: std::time::Duration) -> String {
+    let nanos = d.as_nanos();
+    if nanos < 1000 {
+        return format!("{nanos}ns");
+    }
+    let mut value = nanos as f64;
+    for unit in ["µs", "ms"] {
+        value /= 1000.0;
+        if value < 999.5 {
+            return format!("{}{unit}", three_sig_figs(value));
+        }
+    }
+    format!("{}s", three_sig_figs(value / 1000.0))
+}
+
+fn three_sig_figs(v: f64) -> String {
+    let decimals = if v >= 99.95 {
+        0
+    } else if v >= 9.995 {
+        1
+    } else {
+        2
+    };
+    let s = format!("{v:.decimals$}");
+    if s.contains('.') {
+        s.trim_end_matches('0').trim_end_matches('.').to_string()
+    } else {
+        s
+    }
+}
+
+/// Append a `day,part,ms,timestamp` row (timestamp in Unix seconds) to a profiling CSV,
+/// writing the header first when the file is new.
+pub fn append_profile(path: impl AsRef<Path>, day: u8, part: u8, elapsed_ms: u128) -> Result<()> {
+    let path = path.as_ref();
+    let is_new = !path.exists();
+    let mut file = fs::OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(path)
+        .with_context(|| format!("Opening profile CSV: {}", path.display()))?;
+
+    let timestamp = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    if is_new {
+        writeln!(file, "day,part,ms,timestamp")?;
+    }
+    writeln!(file, "{day},{part},{elapsed_ms},{timestamp}")
+        .with_context(|| format!("Writing profile CSV: {}", path.display()))?;
+    Ok(())
+}
+
 //##################################################################################################
 // Numeric Extraction
 //##################################################################################################
@@ -136,6 +614,62 @@ pub fn uints(input: &str) -> Vec<u64> {
         .collect()
 }
 
+/// Signed integers with their byte span `(value, start, end)` (`end` exclusive, `start` on the
+/// `-` when there is one), so a number can be located, replaced or checked for neighbours. A `-`
+/// only counts as a sign when it doesn't follow a digit (`3-4` is 3 and 4); values that overflow
+/// `i64` are skipped.
+pub fn int_spans(input: &str) -> Vec<(i64, usize, usize)> {
+    number_spans(input, true)
+}
+
+/// Unsigned variant of `int_spans`: every `-` is just text, e.g. a symbol in a schematic grid.
+pub fn uint_spans(input: &str) -> Vec<(u64, usize, usize)> {
+    number_spans(input, false)
+}
+
+fn number_spans<T: std::str::FromStr>(input: &str, signed: bool) -> Vec<(T, usize, usize)> {
+    let bytes = input.as_bytes();
+    let mut out = Vec::new();
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            i += 1;
+            continue;
+        }
+        let mut start = i;
+        if signed
+            && start > 0
+            && bytes[start - 1] == b'-'
+            && !(start > 1 && bytes[start - 2].is_ascii_digit())
+        {
+            start -= 1;
+        }
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        if let Ok(v) = input[start..i].parse::<T>() {
+            out.push((v, start, i));
+        }
+    }
+    out
+}
+
+/// Collect every `name=value` integer binding anywhere in the text (`x=10, y=-3`,
+/// `Sensor at x=2`); whitespace around `=` is allowed. Names are identifiers (`[A-Za-z_]\w*`),
+/// values go through `ints`. A repeated name keeps its last value.
+pub fn parse_var_bindings(input: &str) -> HashMap<String, i64> {
+    // Compiled once per thread; `thread_local!` keeps the 1.63 floor (`OnceLock` is 1.70).
+    thread_local! {
+        static BINDING: regex::Regex = regex::Regex::new(r"([A-Za-z_]\w*)\s*=\s*([+-]?\d+)")
+            .expect("binding pattern is valid");
+    }
+    BINDING.with(|re| {
+        re.captures_iter(input)
+            .filter_map(|c| Some((c[1].to_string(), *ints(&c[2]).first()?)))
+            .collect()
+    })
+}
+
 /// Parse a string into individual numeric digits, ignoring any non-digit characters.
 pub fn digits(input: &str) -> Vec<u8> {
     input
@@ -144,6 +678,27 @@ pub fn digits(input: &str) -> Vec<u8> {
         .collect()
 }
 
+/// Assemble decimal digits (most significant first, values 0-9 as from `digits`) into a number;
+/// `None` if it overflows `u64`. An empty slice gives 0.
+pub fn from_digits(digits: &[u8]) -> Option<u64> {
+    digits
+        .iter()
+        .try_fold(0u64, |acc, &d| acc.checked_mul(10)?.checked_add(d as u64))
+}
+
+/// Sum every digit equal to the digit `offset` positions ahead, wrapping around the end
+/// (`offset = 1` for "next digit", `offset = len / 2` for "halfway around"). Pairs with `digits`.
+pub fn sum_matching_offset(digits: &[u8], offset: usize) -> u64 {
+    let n = digits.len();
+    if n == 0 {
+        return 0;
+    }
+    (0..n)
+        .filter(|&i| digits[i] == digits[(i + offset) % n])
+        .map(|i| digits[i] as u64)
+        .sum()
+}
+
 //##################################################################################################
 // Math Utilities
 //##################################################################################################
@@ -167,185 +722,3112 @@ pub fn lcm(a: i64, b: i64) -> i64 {
     }
 }
 
-//##################################################################################################
-// Grid Primitives
-//##################################################################################################
-
-/// Grid point with integer coordinates (x increases right, y increases down).
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub struct Point {
-    pub x: i64,
-    pub y: i64,
+/// Overflow-safe running total: accumulates in `i128` and only narrows (with a clear error) when
+/// the answer is read back as the `i64` the solvers return. Should even the `i128` overflow, the
+/// sum is marked as overflowed instead of panicking and `to_i64` reports it.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SafeSum {
+    total: i128,
+    overflowed: bool,
 }
 
-impl Point {
-    /// Construct a new point.
-    pub const fn new(x: i64, y: i64) -> Self {
-        Self { x, y }
+impl SafeSum {
+    pub fn new() -> Self {
+        Self::default()
     }
 
-    /// Manhattan distance to another point.
-    pub fn manhattan(self, other: Point) -> i64 {
-        (self.x - other.x).abs() + (self.y - other.y).abs()
+    /// Add a value; an `i128` overflow sets the overflow flag and freezes the total.
+    pub fn add(&mut self, v: impl Into<i128>) {
+        match self.total.checked_add(v.into()) {
+            Some(total) if !self.overflowed => self.total = total,
+            _ => self.overflowed = true,
+        }
     }
 
-    /// 4-neighborhood (right, left, down, up).
-    pub fn neighbors4(self) -> [Point; 4] {
-        [
-            Point::new(self.x + 1, self.y),
-            Point::new(self.x - 1, self.y),
-            Point::new(self.x, self.y + 1),
-            Point::new(self.x, self.y - 1),
-        ]
+    /// The exact total, or `None` once the `i128` accumulator has overflowed.
+    pub fn total(&self) -> Option<i128> {
+        (!self.overflowed).then_some(self.total)
     }
 
-    /// 8-neighborhood (including diagonals).
-    pub fn neighbors8(self) -> [Point; 8] {
-        [
-            Point::new(self.x + 1, self.y),
-            Point::new(self.x - 1, self.y),
-            Point::new(self.x, self.y + 1),
-            Point::new(self.x, self.y - 1),
-            Point::new(self.x + 1, self.y + 1),
-            Point::new(self.x + 1, self.y - 1),
-            Point::new(self.x - 1, self.y + 1),
-            Point::new(self.x - 1, self.y - 1),
-        ]
+    /// The total as `i64`, or an error naming the value that didn't fit (or the `i128` overflow).
+    pub fn to_i64(&self) -> Result<i64> {
+        if self.overflowed {
+            return Err(anyhow!("Sum overflowed i128"));
+        }
+        i64::try_from(self.total).map_err(|_| anyhow!("Sum {} does not fit in i64", self.total))
     }
 }
 
-/// Cardinal directions for grid problems.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
-pub enum Dir4 {
-    Up,
-    Down,
-    Left,
-    Right,
+impl<T: Into<i128>> Extend<T> for SafeSum {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for v in iter {
+            self.add(v);
+        }
+    }
 }
 
-impl Dir4 {
-    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Down, Dir4::Left, Dir4::Right];
+impl<T: Into<i128>> FromIterator<T> for SafeSum {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut sum = SafeSum::new();
+        sum.extend(iter);
+        sum
+    }
+}
 
-    /// Return the delta vector for this direction.
-    pub fn delta(self) -> Point {
-        match self {
-            Dir4::Up => Point::new(0, -1),
-            Dir4::Down => Point::new(0, 1),
-            Dir4::Left => Point::new(-1, 0),
-            Dir4::Right => Point::new(1, 0),
+/// All divisors of `n` in ascending order (trial division up to `sqrt(n)`); empty for 0.
+pub fn divisors(n: u64) -> Vec<u64> {
+    let (mut small, mut large) = (Vec::new(), Vec::new());
+    let mut d = 1;
+    while d <= n / d {
+        if n % d == 0 {
+            small.push(d);
+            if d != n / d {
+                large.push(n / d);
+            }
         }
+        d += 1;
     }
+    small.extend(large.into_iter().rev());
+    small
 }
 
-//##################################################################################################
-// Grid & Graph Helpers
-//##################################################################################################
-
-/// Add two points component-wise.
-pub fn add_point(a: Point, b: Point) -> Point {
-    Point::new(a.x + b.x, a.y + b.y)
+/// Sum of all divisors of `n` (including 1 and `n`).
+pub fn sigma(n: u64) -> u64 {
+    divisors(n).into_iter().sum()
 }
 
-/// Check whether a point lies inside a `width x height` rectangle (origin at top-left, exclusive upper bounds).
-pub fn in_bounds(pt: Point, width: i64, height: i64) -> bool {
-    pt.x >= 0 && pt.x < width && pt.y >= 0 && pt.y < height
+/// Number of divisors of `n`.
+pub fn divisor_count(n: u64) -> usize {
+    divisors(n).len()
 }
 
-/// Count frequency of items in an iterator; returns a `HashMap` of value -> count.
-pub fn counts<T: Eq + std::hash::Hash>(iter: impl IntoIterator<Item = T>) -> HashMap<T, usize> {
-    let mut map = HashMap::new();
-    for item in iter {
-        *map.entry(item).or_insert(0) += 1;
-    }
-    map
+/// A value tracked only by its residues modulo a fixed set of bases, so repeated add / multiply /
+/// square never overflow yet divisibility by any tracked base stays exact (the "monkey worry"
+/// trick without reducing by the LCM by hand).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ModTracker {
+    // (base, value % base)
+    residues: Vec<(u64, u64)>,
 }
 
-/// Multi-source BFS over an unweighted graph; returns a distance map from all starts.
-pub fn bfs_distances<T, I, F>(
-    starts: impl IntoIterator<Item = T>,
-    mut neighbors: F,
-) -> HashMap<T, usize>
-where
-    T: Eq + std::hash::Hash + Copy,
-    F: FnMut(T) -> I,
-    I: IntoIterator<Item = T>,
-{
-    let mut dist = HashMap::new();
-    let mut q = VecDeque::new();
-
-    for s in starts {
-        dist.insert(s, 0);
-        q.push_back(s);
+impl ModTracker {
+    /// Track `value` modulo each of `bases`; panics if a base is 0.
+    pub fn new(bases: &[u64], value: u64) -> Self {
+        assert!(
+            bases.iter().all(|&b| b > 0),
+            "ModTracker bases must be positive"
+        );
+        Self {
+            residues: bases.iter().map(|&b| (b, value % b)).collect(),
+        }
     }
 
-    while let Some(cur) = q.pop_front() {
-        let next_d = dist[&cur] + 1;
-        for nxt in neighbors(cur) {
-            if dist.contains_key(&nxt) {
-                continue;
-            }
-            dist.insert(nxt, next_d);
-            q.push_back(nxt);
+    fn apply(&mut self, f: impl Fn(u128, u128) -> u128) {
+        for (base, r) in &mut self.residues {
+            *r = (f(*r as u128, *base as u128) % *base as u128) as u64;
         }
     }
 
-    dist
-}
+    pub fn add(&mut self, n: u64) {
+        self.apply(|r, b| r + n as u128 % b);
+    }
 
-/// Simple Dijkstra; neighbors yield `(node, cost)` and the function returns the distance map.
+    pub fn mul(&mut self, n: u64) {
+        self.apply(|r, b| r * (n as u128 % b));
+    }
+
+    pub fn square(&mut self) {
+        self.apply(|r, _| r * r);
+    }
+
+    /// The value modulo `base`; `None` if that base is not tracked.
+    pub fn residue(&self, base: u64) -> Option<u64> {
+        self.residues
+            .iter()
+            .find(|&&(b, _)| b == base)
+            .map(|&(_, r)| r)
+    }
+
+    /// Whether the value is divisible by `base`; panics if that base is not tracked.
+    pub fn divisible_by(&self, base: u64) -> bool {
+        match self.residue(base) {
+            Some(r) => r == 0,
+            None => panic!("ModTracker does not track base {base}"),
+        }
+    }
+}
+
+/// Linear congruential generator: `next = (prev * mul + inc) % modulus`, computed in `u128`.
+/// Infinite iterator over the values *after* the seed, e.g. the duelling generators use
+/// `Lcg::new(seed, 16807, 2147483647)` and the handshake transform `Lcg::new(1, subject, 20201227)`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lcg {
+    value: u64,
+    mul: u64,
+    inc: u64,
+    modulus: u64,
+}
+
+impl Lcg {
+    /// Multiplicative generator (`inc = 0`); panics if `modulus` is 0.
+    pub fn new(seed: u64, mul: u64, modulus: u64) -> Self {
+        assert!(modulus > 0, "Lcg modulus must be positive");
+        Self {
+            value: seed,
+            mul,
+            inc: 0,
+            modulus,
+        }
+    }
+
+    /// Add a constant increment to each step.
+    pub fn with_increment(mut self, inc: u64) -> Self {
+        self.inc = inc;
+        self
+    }
+}
+
+impl Iterator for Lcg {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let next =
+            (self.value as u128 * self.mul as u128 + self.inc as u128) % self.modulus as u128;
+        self.value = next as u64;
+        Some(self.value)
+    }
+}
+
+//##################################################################################################
+// Brute-Force Search
+//##################################################################################################
+
+/// Sequential `find` that calls `progress(checked)` every `every` candidates, so minute-long brute
+/// forces can print a spinner or ETA. `every == 0` disables the callback.
+pub fn search_with_progress<T, F, P>(
+    candidates: impl IntoIterator<Item = T>,
+    mut pred: F,
+    every: usize,
+    mut progress: P,
+) -> Option<T>
+where
+    F: FnMut(&T) -> bool,
+    P: FnMut(usize),
+{
+    for (i, c) in candidates.into_iter().enumerate() {
+        if pred(&c) {
+            return Some(c);
+        }
+        if every > 0 && (i + 1) % every == 0 {
+            progress(i + 1);
+        }
+    }
+    None
+}
+
+/// Least `x` in `[lo, hi]` with `pred(x)` true, for a monotone `pred` (false...false, true...true).
+/// Returns `None` if the range is empty or `pred(hi)` is false.
+pub fn binary_search_first_true<F: Fn(i64) -> bool>(lo: i64, hi: i64, pred: F) -> Option<i64> {
+    if lo > hi || !pred(hi) {
+        return None;
+    }
+    let (mut lo, mut hi) = (lo, hi);
+    while lo < hi {
+        let mid = ((lo as i128 + hi as i128).div_euclid(2)) as i64;
+        if pred(mid) {
+            hi = mid;
+        } else {
+            lo = mid + 1;
+        }
+    }
+    Some(lo)
+}
+
+//##################################################################################################
+// Parallel Search (feature = "rayon")
+//##################################################################################################
+
+/// Parallel `find`: returns the first candidate in iteration order satisfying `pred`, so results
+/// match a sequential search (e.g. the lowest matching number of a range).
+#[cfg(feature = "rayon")]
+pub fn par_find<T, I, F>(candidates: I, pred: F) -> Option<T>
+where
+    I: rayon::iter::IntoParallelIterator<Item = T>,
+    T: Send,
+    F: Fn(&T) -> bool + Sync + Send,
+{
+    use rayon::iter::ParallelIterator;
+
+    candidates.into_par_iter().find_first(|c| pred(c))
+}
+
+/// Parallel search without an ordering guarantee; returns whichever match a worker finds first.
+#[cfg(feature = "rayon")]
+pub fn par_find_any<T, I, F>(candidates: I, pred: F) -> Option<T>
+where
+    I: rayon::iter::IntoParallelIterator<Item = T>,
+    T: Send,
+    F: Fn(&T) -> bool + Sync + Send,
+{
+    use rayon::iter::ParallelIterator;
+
+    candidates.into_par_iter().find_any(|c| pred(c))
+}
+
+//##################################################################################################
+// Persistent Memo (feature = "serde")
+//##################################################################################################
+
+/// Memo table that survives between runs: loaded from disk on construction and written back
+/// (bincode) on `flush` or drop when anything changed.
+///
+/// Invalidation is entirely manual: the file has no idea which code produced it, so after
+/// changing what a key means or how values are computed, delete the file (or call `clear`) or
+/// you will keep reading stale answers. A file that no longer deserializes (e.g. the key type
+/// changed) is reported and ignored.
+#[cfg(feature = "serde")]
+pub struct PersistentMemo<K, V>
+where
+    K: serde::Serialize + serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    V: serde::Serialize + serde::de::DeserializeOwned,
+{
+    path: PathBuf,
+    map: HashMap<K, V>,
+    dirty: bool,
+}
+
+#[cfg(feature = "serde")]
+impl<K, V> PersistentMemo<K, V>
+where
+    K: serde::Serialize + serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    V: serde::Serialize + serde::de::DeserializeOwned,
+{
+    /// Memo backed by `Day_XX/memo.bin`.
+    pub fn new(day: u8) -> Result<Self> {
+        Self::at(format!("Day_{day:02}/memo.bin"))
+    }
+
+    /// Memo backed by an arbitrary file (one per puzzle part keeps key types apart).
+    pub fn at(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_path_buf();
+        let map = match fs::read(&path) {
+            Ok(bytes) => bincode::deserialize(&bytes).unwrap_or_else(|e| {
+                eprintln!("Ignoring unreadable memo {}: {e}", path.display());
+                HashMap::new()
+            }),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => HashMap::new(),
+            Err(e) => return Err(e).with_context(|| format!("Reading {}", path.display())),
+        };
+        Ok(Self {
+            path,
+            map,
+            dirty: false,
+        })
+    }
+
+    pub fn get(&self, key: &K) -> Option<&V> {
+        self.map.get(key)
+    }
+
+    pub fn insert(&mut self, key: K, value: V) {
+        self.map.insert(key, value);
+        self.dirty = true;
+    }
+
+    /// Cached value for `key`, computing and storing it on a miss.
+    pub fn get_or_insert_with(&mut self, key: K, f: impl FnOnce() -> V) -> &V {
+        if !self.map.contains_key(&key) {
+            self.dirty = true;
+        }
+        self.map.entry(key).or_insert_with(f)
+    }
+
+    pub fn len(&self) -> usize {
+        self.map.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.map.is_empty()
+    }
+
+    /// Forget every entry (the file is rewritten empty on the next flush).
+    pub fn clear(&mut self) {
+        self.map.clear();
+        self.dirty = true;
+    }
+
+    /// Write the table to disk now if it changed since the last load/flush.
+    pub fn flush(&mut self) -> Result<()> {
+        if !self.dirty {
+            return Ok(());
+        }
+        if let Some(parent) = self.path.parent().filter(|p| !p.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        let bytes = bincode::serialize(&self.map).context("Serializing memo")?;
+        fs::write(&self.path, bytes).with_context(|| format!("Writing {}", self.path.display()))?;
+        self.dirty = false;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<K, V> Drop for PersistentMemo<K, V>
+where
+    K: serde::Serialize + serde::de::DeserializeOwned + Eq + std::hash::Hash,
+    V: serde::Serialize + serde::de::DeserializeOwned,
+{
+    fn drop(&mut self) {
+        if let Err(e) = self.flush() {
+            eprintln!("Warning: could not save memo: {e:#}");
+        }
+    }
+}
+
+//##################################################################################################
+// Number Bases
+//##################################################################################################
+
+/// Parse a signed integer in the given radix (2..=36), e.g. `from_radix("-ff", 16)`; a radix
+/// outside that range is an error.
+pub fn from_radix(s: &str, radix: u32) -> Result<i64> {
+    if !(2..=36).contains(&radix) {
+        return Err(anyhow!("Radix {radix} is outside 2..=36"));
+    }
+    let s = s.trim();
+    i64::from_str_radix(s, radix).with_context(|| format!("Invalid base-{radix} number: '{s}'"))
+}
+
+/// Format a signed integer in the given radix (2..=36) using lowercase digits.
+pub fn to_radix(n: i64, radix: u32) -> String {
+    assert!((2..=36).contains(&radix), "radix must be in 2..=36");
+    if n == 0 {
+        return "0".to_string();
+    }
+    let mut m = n.unsigned_abs();
+    let mut out = Vec::new();
+    while m > 0 {
+        let d = (m % radix as u64) as u32;
+        out.push(std::char::from_digit(d, radix).unwrap_or('?'));
+        m /= radix as u64;
+    }
+    if n < 0 {
+        out.push('-');
+    }
+    out.iter().rev().collect()
+}
+
+/// Parse a SNAFU number (balanced base 5 with digits `=`, `-`, `0`, `1`, `2`); values beyond
+/// `i64` are an error.
+pub fn from_snafu(s: &str) -> Result<i64> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(anyhow!("Empty SNAFU number"));
+    }
+    // Accumulate wider than i64: a prefix may overshoot before later negative digits pull it back.
+    let value = s.chars().try_fold(0i128, |acc, c| {
+        let d = match c {
+            '=' => -2,
+            '-' => -1,
+            '0' => 0,
+            '1' => 1,
+            '2' => 2,
+            _ => return Err(anyhow!("Invalid SNAFU digit '{c}' in '{s}'")),
+        };
+        acc.checked_mul(5)
+            .and_then(|v| v.checked_add(d))
+            .ok_or_else(|| anyhow!("SNAFU number '{s}' overflows i64"))
+    })?;
+    i64::try_from(value).map_err(|_| anyhow!("SNAFU number '{s}' overflows i64"))
+}
+
+/// Format an integer as SNAFU (balanced base 5).
+pub fn to_snafu(mut n: i64) -> String {
+    if n == 0 {
+        return "0".to_string();
+    }
+    let mut out = Vec::new();
+    while n != 0 {
+        let (c, carry) = match n.rem_euclid(5) {
+            0 => ('0', 0),
+            1 => ('1', 0),
+            2 => ('2', 0),
+            3 => ('=', 1),
+            _ => ('-', 1),
+        };
+        out.push(c);
+        n = n.div_euclid(5) + carry;
+    }
+    out.iter().rev().collect()
+}
+
+//##################################################################################################
+// Grid Primitives
+//##################################################################################################
+
+/// Grid point with integer coordinates (x increases right, y increases down).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point {
+    pub x: i64,
+    pub y: i64,
+}
+
+impl Point {
+    /// Construct a new point.
+    pub const fn new(x: i64, y: i64) -> Self {
+        Self { x, y }
+    }
+
+    /// Component-wise sum, or `None` if either coordinate would overflow `i64`.
+    pub fn checked_add(self, other: Point) -> Option<Point> {
+        Some(Point::new(
+            self.x.checked_add(other.x)?,
+            self.y.checked_add(other.y)?,
+        ))
+    }
+
+    /// Manhattan distance to another point.
+    pub fn manhattan(self, other: Point) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    /// 4-neighborhood (right, left, down, up).
+    pub fn neighbors4(self) -> [Point; 4] {
+        [
+            Point::new(self.x + 1, self.y),
+            Point::new(self.x - 1, self.y),
+            Point::new(self.x, self.y + 1),
+            Point::new(self.x, self.y - 1),
+        ]
+    }
+
+    /// 8-neighborhood (including diagonals).
+    pub fn neighbors8(self) -> [Point; 8] {
+        [
+            Point::new(self.x + 1, self.y),
+            Point::new(self.x - 1, self.y),
+            Point::new(self.x, self.y + 1),
+            Point::new(self.x, self.y - 1),
+            Point::new(self.x + 1, self.y + 1),
+            Point::new(self.x + 1, self.y - 1),
+            Point::new(self.x - 1, self.y + 1),
+            Point::new(self.x - 1, self.y - 1),
+        ]
+    }
+
+    /// 4-neighborhood, plus diagonals when `diagonal` is set (same order as `neighbors8`), so
+    /// connectivity can be switched per part without two code paths.
+    pub fn neighbors(self, diagonal: bool) -> impl Iterator<Item = Point> {
+        // `neighbors8` lists the orthogonal neighbours first.
+        self.neighbors8()
+            .into_iter()
+            .take(if diagonal { 8 } else { 4 })
+    }
+}
+
+impl std::fmt::Display for Point {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+/// Cardinal directions for grid problems.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir4 {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Dir4 {
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Down, Dir4::Left, Dir4::Right];
+
+    /// Return the delta vector for this direction.
+    pub fn delta(self) -> Point {
+        match self {
+            Dir4::Up => Point::new(0, -1),
+            Dir4::Down => Point::new(0, 1),
+            Dir4::Left => Point::new(-1, 0),
+            Dir4::Right => Point::new(1, 0),
+        }
+    }
+
+    /// Rotate by `quarter_turns` 90° steps: positive is clockwise, negative counter-clockwise.
+    pub fn rotate(self, quarter_turns: i64) -> Dir4 {
+        const CLOCKWISE: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+        let idx = CLOCKWISE.iter().position(|&d| d == self).unwrap_or(0) as i64;
+        CLOCKWISE[(idx + quarter_turns).rem_euclid(4) as usize]
+    }
+
+    /// Quarter turn clockwise.
+    pub fn turn_right(self) -> Dir4 {
+        self.rotate(1)
+    }
+
+    /// Quarter turn counter-clockwise.
+    pub fn turn_left(self) -> Dir4 {
+        self.rotate(-1)
+    }
+
+    /// Arrow glyph for this direction (`^`, `v`, `<`, `>`).
+    pub fn to_arrow(self) -> char {
+        match self {
+            Dir4::Up => '^',
+            Dir4::Down => 'v',
+            Dir4::Left => '<',
+            Dir4::Right => '>',
+        }
+    }
+}
+
+impl std::fmt::Display for Dir4 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_arrow())
+    }
+}
+
+/// Hex-grid cell in axial coordinates (`q`, `r`; the implicit cube `s = -q - r`).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct HexPoint {
+    pub q: i64,
+    pub r: i64,
+}
+
+impl HexPoint {
+    pub const fn new(q: i64, r: i64) -> Self {
+        Self { q, r }
+    }
+
+    /// The six adjacent cells (same set for flat- and pointy-topped layouts).
+    pub fn neighbors6(self) -> [HexPoint; 6] {
+        [(1, 0), (1, -1), (0, -1), (-1, 0), (-1, 1), (0, 1)]
+            .map(|(dq, dr)| HexPoint::new(self.q + dq, self.r + dr))
+    }
+
+    /// Steps between two cells (cube distance).
+    pub fn distance(self, other: HexPoint) -> i64 {
+        let (dq, dr) = (self.q - other.q, self.r - other.r);
+        (dq.abs() + dr.abs() + (dq + dr).abs()) / 2
+    }
+
+    /// Move one cell on a flat-topped grid: `n`, `ne`, `se`, `s`, `sw`, `nw`.
+    pub fn step(self, dir: &str) -> Result<HexPoint> {
+        let (dq, dr) = match dir {
+            "n" => (0, -1),
+            "ne" => (1, -1),
+            "se" => (1, 0),
+            "s" => (0, 1),
+            "sw" => (-1, 1),
+            "nw" => (-1, 0),
+            _ => return Err(anyhow!("Unknown flat-top hex direction '{dir}'")),
+        };
+        Ok(HexPoint::new(self.q + dq, self.r + dr))
+    }
+
+    /// Move one cell on a pointy-topped grid: `e`, `ne`, `nw`, `w`, `sw`, `se`.
+    /// (`se`/`nw` differ from the flat-topped `step`, so pick the layout the puzzle uses.)
+    pub fn step_pointy(self, dir: &str) -> Result<HexPoint> {
+        let (dq, dr) = match dir {
+            "e" => (1, 0),
+            "ne" => (1, -1),
+            "nw" => (0, -1),
+            "w" => (-1, 0),
+            "sw" => (-1, 1),
+            "se" => (0, 1),
+            _ => return Err(anyhow!("Unknown pointy-top hex direction '{dir}'")),
+        };
+        Ok(HexPoint::new(self.q + dq, self.r + dr))
+    }
+}
+
+/// Direction names accepted by `parse_hex_path`: the flat-topped (`step`) and pointy-topped
+/// (`step_pointy`) sets together.
+pub const HEX_DIRECTIONS: [&str; 8] = ["n", "ne", "e", "se", "s", "sw", "w", "nw"];
+
+/// Split a hex path into direction tokens: comma-separated (`ne,ne,s`) or run together
+/// (`esenee`, where `n`/`s` followed by `e`/`w` form one token). Either way every token must be
+/// one of `HEX_DIRECTIONS`, so a typo fails here rather than halfway through a walk.
+pub fn parse_hex_path(input: &str) -> Result<Vec<&str>> {
+    let input = input.trim();
+    let tokens: Vec<&str> = if input.contains(',') {
+        input
+            .split(',')
+            .map(str::trim)
+            .filter(|t| !t.is_empty())
+            .collect()
+    } else {
+        let mut tokens = Vec::new();
+        let mut chars = input.char_indices().peekable();
+        while let Some((i, c)) = chars.next() {
+            let mut end = i + c.len_utf8();
+            if let ('n' | 's', Some(&(j, 'e' | 'w'))) = (c, chars.peek()) {
+                end = j + 1;
+                chars.next();
+            }
+            tokens.push(&input[i..end]);
+        }
+        tokens
+    };
+    if let Some(bad) = tokens.iter().find(|t| !HEX_DIRECTIONS.contains(t)) {
+        return Err(anyhow!("Unknown hex direction '{bad}' in '{input}'"));
+    }
+    Ok(tokens)
+}
+
+//##################################################################################################
+// Grid Container
+//##################################################################################################
+
+/// Rectangular grid stored row-major and indexed by `Point`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T: Clone> Grid<T> {
+    /// Create a `width x height` grid filled with `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Copy surrounded by `thickness` rows/columns of `pad` on every side, so every real cell has
+    /// in-bounds neighbours. Coordinates shift by `thickness`: original `p` lives at
+    /// `p + Point::new(t, t)` in the new grid (subtract it again to translate results back).
+    pub fn bordered(&self, pad: T, thickness: usize) -> Grid<T> {
+        let width = self.width + 2 * thickness;
+        let mut out = Grid::new(width, self.height + 2 * thickness, pad);
+        for (y, row) in self.cells.chunks(self.width.max(1)).enumerate() {
+            let start = (y + thickness) * width + thickness;
+            out.cells[start..start + row.len()].clone_from_slice(row);
+        }
+        out
+    }
+}
+
+impl<T> Grid<T> {
+    /// Build a grid from rows; errors if the rows are ragged.
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Result<Self> {
+        let height = rows.len();
+        let width = rows.first().map_or(0, |r| r.len());
+        let mut cells = Vec::with_capacity(width * height);
+        for (y, row) in rows.into_iter().enumerate() {
+            if row.len() != width {
+                return Err(anyhow!(
+                    "Ragged grid: row {y} has width {}, expected {width}",
+                    row.len()
+                ));
+            }
+            cells.extend(row);
+        }
+        Ok(Self {
+            width,
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    /// Whether `pt` lies inside the grid.
+    pub fn in_bounds(&self, pt: Point) -> bool {
+        in_bounds(pt, self.width as i64, self.height as i64)
+    }
+
+    /// Cell at `pt`, or `None` when out of bounds.
+    pub fn get(&self, pt: Point) -> Option<&T> {
+        self.in_bounds(pt)
+            .then(|| &self.cells[pt.y as usize * self.width + pt.x as usize])
+    }
+
+    /// Mutable cell at `pt`, or `None` when out of bounds.
+    pub fn get_mut(&mut self, pt: Point) -> Option<&mut T> {
+        if self.in_bounds(pt) {
+            Some(&mut self.cells[pt.y as usize * self.width + pt.x as usize])
+        } else {
+            None
+        }
+    }
+
+    /// All points in row-major order.
+    pub fn points(&self) -> impl Iterator<Item = Point> {
+        let (width, height) = (self.width as i64, self.height as i64);
+        (0..height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Cells of row `y`, left to right; panics if `y` is out of range.
+    pub fn row(&self, y: usize) -> impl Iterator<Item = &T> {
+        assert!(y < self.height, "Row {y} out of range");
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+
+    /// Cells of column `x`, top to bottom; panics if `x` is out of range.
+    pub fn col(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "Column {x} out of range");
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    /// Each row as a slice, top to bottom.
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(move |y| &self.cells[y * self.width..(y + 1) * self.width])
+    }
+
+    /// Each column as an iterator, left to right (no transpose allocation).
+    pub fn cols(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(move |x| self.col(x))
+    }
+
+    /// Points paired with their cells in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = (Point, &T)> {
+        self.points().zip(self.cells.iter())
+    }
+
+    /// Every point whose cell satisfies `pred`, in row-major order.
+    pub fn find_all(&self, pred: impl Fn(&T) -> bool) -> Vec<Point> {
+        self.iter()
+            .filter(|(_, c)| pred(c))
+            .map(|(p, _)| p)
+            .collect()
+    }
+}
+
+impl Grid<char> {
+    /// Parse a character grid (empty lines skipped); errors if rows are ragged.
+    pub fn parse(input: &str) -> Result<Self> {
+        Self::from_rows(parse_char_grid(input))
+    }
+}
+
+impl<T> std::ops::Index<Point> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pt: Point) -> &T {
+        self.get(pt)
+            .unwrap_or_else(|| panic!("Point {pt} out of grid bounds"))
+    }
+}
+
+impl<T> std::ops::IndexMut<Point> for Grid<T> {
+    fn index_mut(&mut self, pt: Point) -> &mut T {
+        self.get_mut(pt)
+            .unwrap_or_else(|| panic!("Point {pt} out of grid bounds"))
+    }
+}
+
+/// Parse a maze and locate each marker char (e.g. `S`/`E`); the last occurrence wins.
+/// When `base` is set, marker cells are overwritten with it (typically `'.'`).
+pub fn parse_maze(
+    input: &str,
+    markers: &[char],
+    base: Option<char>,
+) -> Result<(Grid<char>, HashMap<char, Point>)> {
+    let mut grid = Grid::parse(input)?;
+    let mut found = HashMap::new();
+    for pt in grid.points().collect::<Vec<_>>() {
+        let c = grid[pt];
+        if markers.contains(&c) {
+            found.insert(c, pt);
+            if let Some(b) = base {
+                grid[pt] = b;
+            }
+        }
+    }
+    Ok((grid, found))
+}
+
+/// Char grid that remembers its source formatting (`\r\n` vs `\n`, trailing newline) so
+/// `to_string()` reproduces the input byte for byte, e.g. for rendered-grid answers or diffs.
+/// Mixed line endings are normalized to whichever style the input used first.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridText {
+    pub rows: Vec<Vec<char>>,
+    crlf: bool,
+    trailing_newline: bool,
+}
+
+impl GridText {
+    pub fn parse(input: &str) -> Self {
+        let trailing_newline = input.ends_with('\n');
+        let body = input.strip_suffix('\n').unwrap_or(input);
+        let body = if trailing_newline {
+            body.strip_suffix('\r').unwrap_or(body)
+        } else {
+            body
+        };
+        let rows = if body.is_empty() && trailing_newline {
+            vec![Vec::new()]
+        } else if body.is_empty() {
+            Vec::new()
+        } else {
+            lines(body).map(|l| l.chars().collect()).collect()
+        };
+        Self {
+            rows,
+            crlf: input
+                .find('\n')
+                .map_or(false, |i| input[..i].ends_with('\r')),
+            trailing_newline,
+        }
+    }
+
+    /// Whether the input used `\r\n` line endings.
+    pub fn crlf(&self) -> bool {
+        self.crlf
+    }
+
+    /// Whether the input ended with a newline.
+    pub fn trailing_newline(&self) -> bool {
+        self.trailing_newline
+    }
+}
+
+impl std::fmt::Display for GridText {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let eol = if self.crlf { "\r\n" } else { "\n" };
+        for (i, row) in self.rows.iter().enumerate() {
+            if i > 0 {
+                f.write_str(eol)?;
+            }
+            for &c in row {
+                write!(f, "{c}")?;
+            }
+        }
+        if self.trailing_newline {
+            f.write_str(eol)?;
+        }
+        Ok(())
+    }
+}
+
+//##################################################################################################
+// Grid & Graph Helpers
+//##################################################################################################
+
+/// Add two points component-wise.
+pub fn add_point(a: Point, b: Point) -> Point {
+    Point::new(a.x + b.x, a.y + b.y)
+}
+
+/// Check whether a point lies inside a `width x height` rectangle (origin at top-left, exclusive upper bounds).
+pub fn in_bounds(pt: Point, width: i64, height: i64) -> bool {
+    pt.x >= 0 && pt.x < width && pt.y >= 0 && pt.y < height
+}
+
+/// Wrap a point onto a `width x height` torus, returning `(local_point, tile)` where `tile`
+/// counts how many whole grid copies were crossed on each axis (negative = left/up).
+pub fn wrap_point(pt: Point, width: i64, height: i64) -> (Point, Point) {
+    (
+        Point::new(pt.x.rem_euclid(width), pt.y.rem_euclid(height)),
+        Point::new(pt.x.div_euclid(width), pt.y.div_euclid(height)),
+    )
+}
+
+/// 4-neighborhood on a torus: stepping off one edge re-enters from the opposite side.
+pub fn neighbors4_wrap(pt: Point, width: i64, height: i64) -> [Point; 4] {
+    // Wrap first so stepping from a far-away `pt` cannot overflow.
+    let local = wrap_point(pt, width, height).0;
+    local.neighbors4().map(|n| wrap_point(n, width, height).0)
+}
+
+/// 4-neighborhood of a point on an infinitely tiled map, as `(local_point, tile)` pairs.
+/// `pt` may itself lie outside the base tile; use `Point::new(0, 0)` tiles for the original map.
+/// A neighbour whose coordinates would overflow `i64` comes back as `None`.
+pub fn neighbors4_tiled(pt: Point, width: i64, height: i64) -> [Option<(Point, Point)>; 4] {
+    [Dir4::Right, Dir4::Left, Dir4::Down, Dir4::Up].map(|d| {
+        pt.checked_add(d.delta())
+            .map(|n| wrap_point(n, width, height))
+    })
+}
+
+/// Advance each point by its velocity `steps` times on a `width x height` torus (positions
+/// returned wrapped into the base tile). Works in `i128` modulo the size, so huge step counts
+/// are fine; panics if `points` and `velocities` differ in length.
+pub fn step_wrapping(
+    points: &[Point],
+    velocities: &[Point],
+    width: i64,
+    height: i64,
+    steps: i64,
+) -> Vec<Point> {
+    assert_eq!(points.len(), velocities.len(), "one velocity per point");
+    let advance = |p: i64, v: i64, size: i64| {
+        (p as i128 + v as i128 * steps as i128).rem_euclid(size as i128) as i64
+    };
+    points
+        .iter()
+        .zip(velocities)
+        .map(|(p, v)| Point::new(advance(p.x, v.x, width), advance(p.y, v.y, height)))
+        .collect()
+}
+
+/// Count points per quadrant of a `width x height` grid as `[top-left, top-right, bottom-left,
+/// bottom-right]`; points on the middle row/column (odd sizes) or outside the grid are skipped.
+pub fn quadrant_counts(points: &[Point], width: i64, height: i64) -> [usize; 4] {
+    let (mid_x, mid_y) = (width / 2, height / 2);
+    let mut counts = [0; 4];
+    for &p in points {
+        if !in_bounds(p, width, height)
+            || (width % 2 == 1 && p.x == mid_x)
+            || (height % 2 == 1 && p.y == mid_y)
+        {
+            continue;
+        }
+        let right = p.x >= width - mid_x;
+        let bottom = p.y >= height - mid_y;
+        counts[(bottom as usize) * 2 + right as usize] += 1;
+    }
+    counts
+}
+
+/// Snake-order walk of a `width x height` grid: row 0 left-to-right, row 1 right-to-left, ...
+pub fn boustrophedon(width: usize, height: usize) -> impl Iterator<Item = Point> {
+    (0..height).flat_map(move |y| {
+        (0..width).map(move |i| {
+            let x = if y % 2 == 0 { i } else { width - 1 - i };
+            Point::new(x as i64, y as i64)
+        })
+    })
+}
+
+/// Endless square-spiral (Ulam) walk starting at `center`: right 1, up 1, left 2, down 2,
+/// right 3, ... so the n-th item (0-based) is square `n + 1` of the spiral-memory layout.
+pub fn spiral_from(center: Point) -> impl Iterator<Item = Point> {
+    const TURNS: [Dir4; 4] = [Dir4::Right, Dir4::Up, Dir4::Left, Dir4::Down];
+    let mut pos = center;
+    let (mut leg, mut taken, mut turn) = (1, 0, 0);
+    std::iter::once(center).chain(std::iter::from_fn(move || {
+        pos = add_point(pos, TURNS[turn].delta());
+        taken += 1;
+        if taken == leg {
+            taken = 0;
+            turn = (turn + 1) % 4;
+            // Leg length grows after every second turn.
+            if turn % 2 == 0 {
+                leg += 1;
+            }
+        }
+        Some(pos)
+    }))
+}
+
+/// Closed-form position of square `n` (1-based, `1` at the origin) in the `spiral_from` layout,
+/// so `spiral_coord(n).manhattan(origin)` needs no walk. Panics for `n == 0`.
+pub fn spiral_coord(n: u64) -> Point {
+    assert!(n > 0, "spiral squares start at 1");
+    // Float sqrt, then nudge to the exact floor root (u64::isqrt needs Rust 1.84).
+    let mut root = (n as f64).sqrt() as u64;
+    while root.checked_mul(root).map_or(true, |sq| sq > n) {
+        root -= 1;
+    }
+    while (root + 1).checked_mul(root + 1).map_or(false, |sq| sq <= n) {
+        root += 1;
+    }
+    if root * root < n {
+        root += 1;
+    }
+    let k = (root / 2) as i64;
+    if k == 0 {
+        return Point::new(0, 0);
+    }
+    // Offset within ring k, which starts just right of the previous ring's last square.
+    let m = (n - (2 * k as u64 - 1).pow(2)) as i64;
+    let (seg, t) = ((m - 1) / (2 * k), (m - 1) % (2 * k) + 1);
+    match seg {
+        0 => Point::new(k, k - t),
+        1 => Point::new(k - t, -k),
+        2 => Point::new(-k, -k + t),
+        _ => Point::new(-k + t, k),
+    }
+}
+
+/// Count frequency of items in an iterator; returns a `HashMap` of value -> count.
+pub fn counts<T: Eq + std::hash::Hash>(iter: impl IntoIterator<Item = T>) -> HashMap<T, usize> {
+    let mut map = HashMap::new();
+    for item in iter {
+        *map.entry(item).or_insert(0) += 1;
+    }
+    map
+}
+
+/// Like `counts`, but tallies `key(item)` (e.g. counting numbers by parity).
+pub fn counts_by<T, K, F>(iter: impl IntoIterator<Item = T>, key: F) -> HashMap<K, usize>
+where
+    K: Eq + std::hash::Hash,
+    F: Fn(&T) -> K,
+{
+    counts(iter.into_iter().map(|item| key(&item)))
+}
+
+/// Signed per-item difference `a - b` of two multisets (as produced by `counts`); items with
+/// equal counts are omitted, so an empty result means the bags match.
+pub fn multiset_diff<T: Eq + std::hash::Hash + Clone>(
+    a: &HashMap<T, usize>,
+    b: &HashMap<T, usize>,
+) -> HashMap<T, isize> {
+    let mut diff: HashMap<T, isize> = a.iter().map(|(k, &n)| (k.clone(), n as isize)).collect();
+    for (k, &n) in b {
+        *diff.entry(k.clone()).or_insert(0) -= n as isize;
+    }
+    diff.retain(|_, d| *d != 0);
+    diff
+}
+
+/// Items present in both multisets, each with the smaller of its two counts.
+pub fn multiset_intersection<T: Eq + std::hash::Hash + Clone>(
+    a: &HashMap<T, usize>,
+    b: &HashMap<T, usize>,
+) -> HashMap<T, usize> {
+    a.iter()
+        .filter_map(|(k, &n)| b.get(k).map(|&m| (k.clone(), n.min(m))))
+        .collect()
+}
+
+/// Multi-source BFS over an unweighted graph; returns a distance map from all starts.
+pub fn bfs_distances<T, I, F>(
+    starts: impl IntoIterator<Item = T>,
+    mut neighbors: F,
+) -> HashMap<T, usize>
+where
+    T: Eq + std::hash::Hash + Copy,
+    F: FnMut(T) -> I,
+    I: IntoIterator<Item = T>,
+{
+    let mut dist = HashMap::new();
+    let mut q = VecDeque::new();
+
+    for s in starts {
+        dist.insert(s, 0);
+        q.push_back(s);
+    }
+
+    while let Some(cur) = q.pop_front() {
+        let next_d = dist[&cur] + 1;
+        for nxt in neighbors(cur) {
+            if dist.contains_key(&nxt) {
+                continue;
+            }
+            dist.insert(nxt, next_d);
+            q.push_back(nxt);
+        }
+    }
+
+    dist
+}
+
+/// Multi-source BFS grouped by wave: `result[k]` holds every node at distance `k` (starts first).
+pub fn bfs_layers<T, I, F>(starts: impl IntoIterator<Item = T>, mut neighbors: F) -> Vec<Vec<T>>
+where
+    T: Eq + std::hash::Hash + Copy,
+    F: FnMut(T) -> I,
+    I: IntoIterator<Item = T>,
+{
+    let mut seen = HashSet::new();
+    let mut layer: Vec<T> = starts.into_iter().filter(|&s| seen.insert(s)).collect();
+    let mut layers = Vec::new();
+
+    while !layer.is_empty() {
+        let next = next_layer(&layer, &mut seen, &mut neighbors);
+        layers.push(std::mem::replace(&mut layer, next));
+    }
+
+    layers
+}
+
+/// Nodes at BFS distance exactly `k` from `start`; stops expanding once that layer is reached,
+/// so nothing beyond depth `k` is visited or stored (empty if the graph runs out first).
+pub fn frontier_at<T, I, F>(start: T, k: usize, mut neighbors: F) -> HashSet<T>
+where
+    T: Eq + std::hash::Hash + Copy,
+    F: FnMut(T) -> I,
+    I: IntoIterator<Item = T>,
+{
+    let mut seen = HashSet::from([start]);
+    let mut layer = vec![start];
+    for _ in 0..k {
+        if layer.is_empty() {
+            break;
+        }
+        layer = next_layer(&layer, &mut seen, &mut neighbors);
+    }
+    layer.into_iter().collect()
+}
+
+/// One BFS wave: unseen neighbours of `layer`, marked as seen.
+fn next_layer<T, I, F>(layer: &[T], seen: &mut HashSet<T>, neighbors: &mut F) -> Vec<T>
+where
+    T: Eq + std::hash::Hash + Copy,
+    F: FnMut(T) -> I,
+    I: IntoIterator<Item = T>,
+{
+    let mut next = Vec::new();
+    for &cur in layer {
+        for nxt in neighbors(cur) {
+            if seen.insert(nxt) {
+                next.push(nxt);
+            }
+        }
+    }
+    next
+}
+
+/// BFS step count through a char maze where `.` cells are open and each `portals` key is also
+/// adjacent to its value (one step, like the donut maze). Map both directions for two-way portals.
+pub fn bfs_with_portals(
+    grid: &[Vec<char>],
+    portals: &HashMap<Point, Point>,
+    start: Point,
+    goal: Point,
+) -> Option<usize> {
+    let open = |p: Point| grid_cell(grid, p) == Some(&'.');
+    bfs_distances([start], |p: Point| {
+        p.neighbors4()
+            .into_iter()
+            .chain(portals.get(&p).copied())
+            .filter(|&n| open(n))
+            .collect::<Vec<_>>()
+    })
+    .get(&goal)
+    .copied()
+}
+
+/// Cells a walker can stand on after exactly `steps` moves (4-connected, over `passable` cells).
+/// Since stepping back and forth wastes two moves, that is every cell whose BFS distance is
+/// `<= steps` *and* has the same parity as `steps`; odd and even cells alternate like a
+/// checkerboard, so the other parity is never reachable at the end. The BFS stops at the budget,
+/// so `passable` may describe an infinite (e.g. tiled) garden.
+pub fn reachable_within<F: Fn(Point) -> bool>(start: Point, steps: usize, passable: F) -> usize {
+    let mut seen = HashSet::from([start]);
+    let mut frontier = vec![start];
+    let mut count = usize::from(steps % 2 == 0);
+    for d in 1..=steps {
+        let mut next = Vec::new();
+        for p in frontier {
+            for n in p.neighbors4() {
+                if passable(n) && seen.insert(n) {
+                    next.push(n);
+                }
+            }
+        }
+        if d % 2 == steps % 2 {
+            count += next.len();
+        }
+        if next.is_empty() {
+            break;
+        }
+        frontier = next;
+    }
+    count
+}
+
+/// Simple Dijkstra; neighbors yield `(node, cost)` and the function returns the distance map.
 /// Meant for small/medium AoC graphs—no early-exit target to keep the API minimal.
-pub fn dijkstra<T, I, F>(start: T, mut neighbors: F) -> HashMap<T, u64>
+pub fn dijkstra<T, I, F>(start: T, neighbors: F) -> HashMap<T, u64>
+where
+    T: Eq + std::hash::Hash + Copy + Ord,
+    F: FnMut(T) -> I,
+    I: IntoIterator<Item = (T, u64)>,
+{
+    dijkstra_until(start, neighbors, |_| false).0
+}
+
+/// Dijkstra that stops as soon as any node in `goals` is popped, returning that goal and its cost
+/// ("nearest exit / key"). Ties at equal cost go to the smaller node per `Ord`.
+pub fn dijkstra_nearest<T, I, F>(start: T, goals: &HashSet<T>, neighbors: F) -> Option<(T, u64)>
+where
+    T: Eq + std::hash::Hash + Copy + Ord,
+    F: FnMut(T) -> I,
+    I: IntoIterator<Item = (T, u64)>,
+{
+    dijkstra_until(start, neighbors, |node| goals.contains(&node)).1
+}
+
+/// Shared Dijkstra loop: returns the distances settled so far and the first popped node for
+/// which `stop` holds (with its cost), if any.
+fn dijkstra_until<T, I, F, S>(
+    start: T,
+    mut neighbors: F,
+    mut stop: S,
+) -> (HashMap<T, u64>, Option<(T, u64)>)
+where
+    T: Eq + std::hash::Hash + Copy + Ord,
+    F: FnMut(T) -> I,
+    I: IntoIterator<Item = (T, u64)>,
+    S: FnMut(T) -> bool,
+{
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+
+    let mut dist: HashMap<T, u64> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+    dist.insert(start, 0);
+    heap.push((Reverse(0u64), Reverse(start)));
+
+    while let Some((Reverse(d), Reverse(node))) = heap.pop() {
+        if d != dist[&node] {
+            continue; // stale entry
+        }
+        if stop(node) {
+            return (dist, Some((node, d)));
+        }
+        for (nxt, w) in neighbors(node) {
+            let nd = d + w;
+            let entry = dist.entry(nxt).or_insert(u64::MAX);
+            if nd < *entry {
+                *entry = nd;
+                heap.push((Reverse(nd), Reverse(nxt)));
+            }
+        }
+    }
+
+    (dist, None)
+}
+
+/// Dijkstra over arbitrary `Clone + Hash` states (e.g. a `(pos, dir, run)` struct), so nothing has
+/// to be bit-packed into a `Copy + Ord` key. States are interned to indices, and the heap orders
+/// `(cost, index)` pairs, so `S` itself never needs an ordering.
+pub fn dijkstra_state<S, I, F>(start: S, mut neighbors: F) -> HashMap<S, u64>
+where
+    S: Clone + Eq + std::hash::Hash,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = (S, u64)>,
+{
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+
+    let mut ids: HashMap<S, usize> = HashMap::from([(start.clone(), 0)]);
+    let mut states = vec![start];
+    let mut dist = vec![0u64];
+    let mut heap = BinaryHeap::from([(Reverse(0u64), 0usize)]);
+
+    while let Some((Reverse(d), id)) = heap.pop() {
+        if d != dist[id] {
+            continue; // stale entry
+        }
+        for (nxt, w) in neighbors(&states[id]) {
+            let nd = d + w;
+            let nid = *ids.entry(nxt).or_insert_with_key(|key| {
+                states.push(key.clone());
+                dist.push(u64::MAX);
+                states.len() - 1
+            });
+            if nd < dist[nid] {
+                dist[nid] = nd;
+                heap.push((Reverse(nd), nid));
+            }
+        }
+    }
+
+    states.into_iter().zip(dist).collect()
+}
+
+/// A* search from `start` to `goal`; `heuristic` must never overestimate the remaining cost.
+/// Returns the total cost and the path (both endpoints included).
+pub fn astar<T, I, F, H>(start: T, goal: T, mut neighbors: F, heuristic: H) -> Option<(u64, Vec<T>)>
 where
     T: Eq + std::hash::Hash + Copy + Ord,
     F: FnMut(T) -> I,
     I: IntoIterator<Item = (T, u64)>,
+    H: Fn(T) -> u64,
+{
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+
+    let mut dist: HashMap<T, u64> = HashMap::new();
+    let mut prev: HashMap<T, T> = HashMap::new();
+    let mut heap = BinaryHeap::new();
+    dist.insert(start, 0);
+    heap.push((Reverse(heuristic(start)), Reverse(0u64), start));
+
+    while let Some((_, Reverse(d), node)) = heap.pop() {
+        if node == goal {
+            let mut path = vec![goal];
+            let mut cur = goal;
+            while let Some(&p) = prev.get(&cur) {
+                path.push(p);
+                cur = p;
+            }
+            path.reverse();
+            return Some((d, path));
+        }
+        if d != dist[&node] {
+            continue; // stale entry
+        }
+        for (nxt, w) in neighbors(node) {
+            let nd = d + w;
+            let entry = dist.entry(nxt).or_insert(u64::MAX);
+            if nd < *entry {
+                *entry = nd;
+                prev.insert(nxt, node);
+                heap.push((Reverse(nd + heuristic(nxt)), Reverse(nd), nxt));
+            }
+        }
+    }
+
+    None
+}
+
+/// Best-first search: always expands the queued state with the lowest `priority` (ties in
+/// insertion order) and returns the first state satisfying `is_goal`. Each state is expanded once.
+/// Unlike `astar`/`dijkstra`, the ordering is arbitrary, so non-additive or greedy scores work.
+pub fn best_first<S, G, F, I, H>(start: S, is_goal: G, mut successors: F, priority: H) -> Option<S>
+where
+    S: Clone + Eq + std::hash::Hash,
+    G: Fn(&S) -> bool,
+    F: FnMut(&S) -> I,
+    I: IntoIterator<Item = S>,
+    H: Fn(&S) -> u64,
 {
     use std::cmp::Reverse;
     use std::collections::BinaryHeap;
 
-    let mut dist: HashMap<T, u64> = HashMap::new();
-    let mut heap = BinaryHeap::new();
-    dist.insert(start, 0);
-    heap.push((Reverse(0u64), start));
+    // The heap holds indices into `states` so `S` needn't be `Ord`.
+    let mut states = vec![start.clone()];
+    let mut heap = BinaryHeap::from([(Reverse(priority(&start)), Reverse(0usize))]);
+    let mut seen = HashSet::from([start]);
+
+    while let Some((_, Reverse(idx))) = heap.pop() {
+        let state = states[idx].clone();
+        if is_goal(&state) {
+            return Some(state);
+        }
+        for nxt in successors(&state) {
+            if seen.insert(nxt.clone()) {
+                heap.push((Reverse(priority(&nxt)), Reverse(states.len())));
+                states.push(nxt);
+            }
+        }
+    }
+
+    None
+}
+
+/// Sentinel cell value `astar_grid` treats as impassable.
+pub const WALL: u8 = u8::MAX;
+
+/// A* over a byte grid: entering a cell costs its value, `WALL` cells are skipped, moves are
+/// 4-connected and the heuristic is Manhattan distance (admissible while every cost is >= 1).
+/// Rows may be ragged; cells past the end of a row are off the grid.
+pub fn astar_grid(grid: &[Vec<u8>], start: Point, goal: Point) -> Option<(u64, Vec<Point>)> {
+    grid_cell(grid, start)?;
+    grid_cell(grid, goal)?;
+
+    astar(
+        start,
+        goal,
+        |p: Point| {
+            p.neighbors4()
+                .into_iter()
+                .filter_map(|n| {
+                    let &cost = grid_cell(grid, n)?;
+                    (cost != WALL).then_some((n, cost as u64))
+                })
+                .collect::<Vec<_>>()
+        },
+        |p| p.manhattan(goal) as u64,
+    )
+}
+
+/// Dijkstra over a cost grid: entering a cell costs its value, moves are 4-connected.
+/// Returns the cheapest total cost from `start` to `goal` (the start cell is free). Rows may be
+/// ragged; cells past the end of a row are off the grid.
+pub fn dijkstra_grid(costs: &[Vec<u64>], start: Point, goal: Point) -> Option<u64> {
+    grid_cell(costs, start)?;
+    grid_cell(costs, goal)?;
+
+    let dist = dijkstra(start, |p: Point| {
+        p.neighbors4()
+            .into_iter()
+            .filter_map(|n| Some((n, *grid_cell(costs, n)?)))
+            .collect::<Vec<_>>()
+    });
+    dist.get(&goal).copied()
+}
+
+/// Cheapest path over a cost grid (entering a cell costs its value) where the walker must go at
+/// least `min_straight` and at most `max_straight` cells in a line before turning or stopping,
+/// and may never reverse (the crucible rule: `(1, 3)` for part 1, `(4, 10)` for part 2).
+/// Runs `astar` over `(point, heading, run_length)` states with a Manhattan heuristic scaled by
+/// the cheapest cell, so the state space stays bounded by `width * height * 4 * max_straight`.
+/// Rows may be ragged; cells past the end of a row are off the grid.
+pub fn min_cost_path_constrained(
+    costs: &[Vec<u64>],
+    start: Point,
+    goal: Point,
+    min_straight: usize,
+    max_straight: usize,
+) -> Option<u64> {
+    grid_cell(costs, start)?;
+    grid_cell(costs, goal)?;
+    if start == goal {
+        return Some(0);
+    }
+    let cheapest = costs.iter().flatten().copied().min().unwrap_or(0);
+
+    // State: (point, index into Dir4::ALL or NONE before the first move, straight run length).
+    // Reaching the goal with a long enough run steps for free into the (goal, NONE, 0) sink,
+    // which `astar` can then target as a single node.
+    const NONE: usize = 4;
+    let sink = (goal, NONE, 0);
+    let result = astar(
+        (start, NONE, 0),
+        sink,
+        |(p, heading, run): (Point, usize, usize)| {
+            let mut out = Vec::new();
+            if p == goal && run >= min_straight {
+                out.push((sink, 0));
+            }
+            for (i, d) in Dir4::ALL.into_iter().enumerate() {
+                let straight = i == heading;
+                let allowed = if heading == NONE {
+                    true
+                } else if straight {
+                    run < max_straight
+                } else {
+                    d != Dir4::ALL[heading].rotate(2) && run >= min_straight
+                };
+                let n = add_point(p, d.delta());
+                if let (true, Some(&cost)) = (allowed, grid_cell(costs, n)) {
+                    let next_run = if straight { run + 1 } else { 1 };
+                    out.push(((n, i, next_run), cost));
+                }
+            }
+            out
+        },
+        |(p, _, _)| p.manhattan(goal) as u64 * cheapest,
+    );
+    result.map(|(cost, _)| cost)
+}
+
+/// Adjacency map keyed by node name; each entry lists `(neighbor, weight)`.
+pub type Graph = HashMap<String, Vec<(String, u64)>>;
+
+/// Parse an edge list into an adjacency map. Recognized line formats:
+/// `a-b` (weight 1) and `a to b = w`. Undirected edges are inserted both ways.
+pub fn parse_edges(input: &str, directed: bool) -> Result<Graph> {
+    let mut graph: Graph = HashMap::new();
+    for line in lines(input).map(str::trim).filter(|l| !l.is_empty()) {
+        let (a, b, w) = if let Some((ends, weight)) = line.split_once(" = ") {
+            let (a, b) = ends
+                .split_once(" to ")
+                .ok_or_else(|| anyhow!("Unrecognized edge line: {line}"))?;
+            let w = weight
+                .trim()
+                .parse::<u64>()
+                .with_context(|| format!("Invalid edge weight in line: {line}"))?;
+            (a.trim(), b.trim(), w)
+        } else if let Some((a, b)) = line.split_once('-') {
+            (a.trim(), b.trim(), 1)
+        } else {
+            return Err(anyhow!("Unrecognized edge line: {line}"));
+        };
+
+        graph
+            .entry(a.to_string())
+            .or_default()
+            .push((b.to_string(), w));
+        if directed {
+            graph.entry(b.to_string()).or_default();
+        } else {
+            graph
+                .entry(b.to_string())
+                .or_default()
+                .push((a.to_string(), w));
+        }
+    }
+    Ok(graph)
+}
+
+/// Minimum spanning tree over the complete graph on `nodes` (Kruskal with `DisjointSet`).
+/// Returns the total weight and the chosen edges in the order they were taken; a star map just
+/// needs `|a, b| a.manhattan(b) as u64`. O(n^2 log n), fine for a few thousand nodes.
+pub fn mst<T: Eq + std::hash::Hash + Copy>(
+    nodes: &[T],
+    weight: impl Fn(T, T) -> u64,
+) -> (u64, Vec<(T, T)>) {
+    let mut edges = Vec::with_capacity(nodes.len() * nodes.len().saturating_sub(1) / 2);
+    for i in 0..nodes.len() {
+        for j in i + 1..nodes.len() {
+            edges.push((weight(nodes[i], nodes[j]), i, j));
+        }
+    }
+    edges.sort_unstable();
+
+    let mut sets = DisjointSet::new(nodes.len());
+    let mut total = 0;
+    let mut chosen = Vec::with_capacity(nodes.len().saturating_sub(1));
+    for (w, i, j) in edges {
+        if sets.union(i, j) {
+            total += w;
+            chosen.push((nodes[i], nodes[j]));
+            if chosen.len() + 1 == nodes.len() {
+                break;
+            }
+        }
+    }
+    (total, chosen)
+}
+
+/// Transpose a rectangular matrix (allocates a new Vec<Vec<T>>); panics if rows are ragged.
+pub fn transpose<T: Clone>(grid: &[Vec<T>]) -> Vec<Vec<T>> {
+    if grid.is_empty() {
+        return Vec::new();
+    }
+    let rows = grid.len();
+    let cols = grid[0].len();
+    let mut out = vec![vec![grid[0][0].clone(); rows]; cols];
+    for r in 0..rows {
+        for c in 0..cols {
+            out[c][r] = grid[r][c].clone();
+        }
+    }
+    out
+}
+
+/// Cells that differ between two same-sized grids as `(point, old, new)` in row-major order.
+/// Panics if the grids have different dimensions.
+pub fn grid_diff<T: PartialEq + Clone>(before: &[Vec<T>], after: &[Vec<T>]) -> Vec<(Point, T, T)> {
+    assert_eq!(before.len(), after.len(), "grid_diff: row counts differ");
+    let mut changed = Vec::new();
+    for (y, (old_row, new_row)) in before.iter().zip(after).enumerate() {
+        assert_eq!(
+            old_row.len(),
+            new_row.len(),
+            "grid_diff: row {y} widths differ"
+        );
+        for (x, (old, new)) in old_row.iter().zip(new_row).enumerate() {
+            if old != new {
+                changed.push((Point::new(x as i64, y as i64), old.clone(), new.clone()));
+            }
+        }
+    }
+    changed
+}
+
+/// One cellular-automaton generation without allocating: `rule(old_grid, point)` returns the new
+/// value of each cell, written into `scratch`, after which the buffers are swapped so `grid` holds
+/// the new state. Reuse the same `scratch` every call; it is reshaped to match `grid` if needed.
+pub fn step_grid_in_place<T: Clone>(
+    grid: &mut Vec<Vec<T>>,
+    scratch: &mut Vec<Vec<T>>,
+    rule: impl Fn(&[Vec<T>], Point) -> T,
+) {
+    let same_shape = scratch.len() == grid.len()
+        && scratch
+            .iter()
+            .zip(grid.iter())
+            .all(|(a, b)| a.len() == b.len());
+    if !same_shape {
+        scratch.clone_from(grid);
+    }
+    for (y, row) in scratch.iter_mut().enumerate() {
+        for (x, cell) in row.iter_mut().enumerate() {
+            *cell = rule(grid, Point::new(x as i64, y as i64));
+        }
+    }
+    std::mem::swap(grid, scratch);
+}
+
+/// 64-bit FNV-1a hash of a byte grid (row breaks included), for keying cycle detection on a `u64`
+/// instead of cloning whole grids into a `HashSet`. Distinct grids collide with probability around
+/// 2^-64 per pair, negligible for the few thousand states a cycle search visits.
+pub fn grid_fingerprint(grid: &[Vec<u8>]) -> u64 {
+    const FNV_OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let mix = |h: u64, b: u8| (h ^ b as u64).wrapping_mul(FNV_PRIME);
+    grid.iter().fold(FNV_OFFSET, |h, row| {
+        // Hash the row length so `["ab", "c"]` and `["a", "bc"]` differ.
+        let h = (row.len() as u64).to_le_bytes().into_iter().fold(h, mix);
+        row.iter().copied().fold(h, mix)
+    })
+}
+
+/// 4-connected regions of equal cells. The order is deterministic: regions come in row-major
+/// order of their first (top-left-most) cell and each region's points are row-major too.
+pub fn connected_components<T: PartialEq>(grid: &[Vec<T>]) -> Vec<Vec<Point>> {
+    let mut seen: Vec<Vec<bool>> = grid.iter().map(|row| vec![false; row.len()]).collect();
+    let mut components = Vec::new();
+
+    for (y, row) in grid.iter().enumerate() {
+        for x in 0..row.len() {
+            if seen[y][x] {
+                continue;
+            }
+            seen[y][x] = true;
+            let mut region = Vec::new();
+            let mut q = VecDeque::from([Point::new(x as i64, y as i64)]);
+            while let Some(cur) = q.pop_front() {
+                region.push(cur);
+                for n in cur.neighbors4() {
+                    let Some(cell) = grid_cell(grid, n) else {
+                        continue;
+                    };
+                    let (nx, ny) = (n.x as usize, n.y as usize);
+                    if !seen[ny][nx] && *cell == grid[y][x] {
+                        seen[ny][nx] = true;
+                        q.push_back(n);
+                    }
+                }
+            }
+            region.sort_unstable_by_key(|p| (p.y, p.x));
+            components.push(region);
+        }
+    }
+    components
+}
+
+/// `(plant, area, perimeter)` per region; perimeter counts edges facing another plant or the border.
+pub fn region_metrics(grid: &[Vec<char>]) -> Vec<(char, usize, usize)> {
+    connected_components(grid)
+        .into_iter()
+        .map(|region| {
+            let plant = grid[region[0].y as usize][region[0].x as usize];
+            let perimeter = region
+                .iter()
+                .flat_map(|p| p.neighbors4())
+                .filter(|&n| grid_cell(grid, n) != Some(&plant))
+                .count();
+            (plant, region.len(), perimeter)
+        })
+        .collect()
+}
+
+/// `(plant, area, sides)` per region, where `sides` counts straight fence runs.
+/// Uses the corners == sides identity: each cell checks its four diagonal corners.
+pub fn region_sides(grid: &[Vec<char>]) -> Vec<(char, usize, usize)> {
+    connected_components(grid)
+        .into_iter()
+        .map(|region| {
+            let plant = grid[region[0].y as usize][region[0].x as usize];
+            let same = |p: Point| grid_cell(grid, p) == Some(&plant);
+            let mut corners = 0;
+            for &p in &region {
+                for (dx, dy) in [(1, 1), (1, -1), (-1, 1), (-1, -1)] {
+                    let horiz = same(Point::new(p.x + dx, p.y));
+                    let vert = same(Point::new(p.x, p.y + dy));
+                    let diag = same(Point::new(p.x + dx, p.y + dy));
+                    if (!horiz && !vert) || (horiz && vert && !diag) {
+                        corners += 1;
+                    }
+                }
+            }
+            (plant, region.len(), corners)
+        })
+        .collect()
+}
+
+/// Horizontal mirror line: returns `k` when the grid reflects between rows `k - 1` and `k`
+/// (i.e. `k` rows lie above the line). Rows beyond the shorter side are ignored.
+pub fn find_horizontal_reflection(grid: &[Vec<char>]) -> Option<usize> {
+    reflection_line(grid.len(), |a, b| row_diff(grid, a, b), 0)
+}
+
+/// Vertical mirror line: returns `k` when the grid reflects between columns `k - 1` and `k`.
+pub fn find_vertical_reflection(grid: &[Vec<char>]) -> Option<usize> {
+    let width = grid.first().map_or(0, |r| r.len());
+    reflection_line(width, |a, b| col_diff(grid, a, b), 0)
+}
+
+/// Like `find_horizontal_reflection`, but the reflection must differ in exactly one cell.
+pub fn find_horizontal_reflection_with_smudge(grid: &[Vec<char>]) -> Option<usize> {
+    reflection_line(grid.len(), |a, b| row_diff(grid, a, b), 1)
+}
+
+/// Like `find_vertical_reflection`, but the reflection must differ in exactly one cell.
+pub fn find_vertical_reflection_with_smudge(grid: &[Vec<char>]) -> Option<usize> {
+    let width = grid.first().map_or(0, |r| r.len());
+    reflection_line(width, |a, b| col_diff(grid, a, b), 1)
+}
+
+/// First line `k` in `1..n` whose mirrored pairs differ in exactly `smudges` cells in total.
+fn reflection_line(
+    n: usize,
+    diff: impl Fn(usize, usize) -> usize,
+    smudges: usize,
+) -> Option<usize> {
+    (1..n).find(|&k| {
+        let mut total = 0;
+        for i in 0..k.min(n - k) {
+            total += diff(k - 1 - i, k + i);
+            if total > smudges {
+                return false;
+            }
+        }
+        total == smudges
+    })
+}
+
+/// Read the number whose ASCII digits start at `start` and run in direction `dir` (down a column,
+/// along a diagonal, ...), stopping at the grid edge or the first non-digit cell. `None` when
+/// `start` is not a digit or the value overflows `u64`.
+pub fn read_number_along(grid: &[Vec<u8>], start: Point, dir: Point) -> Option<u64> {
+    let mut found = Vec::new();
+    let mut pt = Some(start);
+    while let Some(&cell) = pt.and_then(|p| grid_cell(grid, p)) {
+        if !cell.is_ascii_digit() {
+            break;
+        }
+        found.push(cell - b'0');
+        // A zero `dir` would revisit `start` forever; treat it as a single-cell read.
+        pt = pt
+            .filter(|_| dir != Point::new(0, 0))
+            .and_then(|p| p.checked_add(dir));
+    }
+    if found.is_empty() {
+        return None;
+    }
+    from_digits(&found)
+}
+
+/// Every number in a char grid (row-major order, read with `uint_spans` so `-` stays a symbol)
+/// with the cells 8-adjacent to any of its digits that satisfy `is_symbol` (deduplicated,
+/// row-major). Numbers touching no symbol are kept with an empty list. Values stay `u64`, so no
+/// digit run is reinterpreted as negative; runs too long for `u64` are skipped, as in `uint_spans`.
+pub fn numbers_adjacent_to(
+    grid: &[Vec<char>],
+    is_symbol: impl Fn(char) -> bool,
+) -> Vec<(u64, Vec<Point>)> {
+    let mut out = Vec::new();
+    for (y, row) in grid.iter().enumerate() {
+        let text: String = row.iter().collect();
+        for (value, start, end) in uint_spans(&text) {
+            let first = text[..start].chars().count() as i64;
+            let len = text[start..end].chars().count() as i64;
+            let y = y as i64;
+            let mut symbols: Vec<Point> = (y - 1..=y + 1)
+                .flat_map(|sy| (first - 1..=first + len).map(move |sx| Point::new(sx, sy)))
+                .filter(|&p| grid_cell(grid, p).map_or(false, |&c| is_symbol(c)))
+                .collect();
+            symbols.sort_unstable_by_key(|p| (p.y, p.x));
+            out.push((value, symbols));
+        }
+    }
+    out
+}
+
+/// Reverse index of `numbers_adjacent_to`: each symbol cell with the numbers touching it (in the
+/// same row-major order), e.g. gears are `*` cells with exactly two numbers.
+pub fn numbers_by_symbol(numbers: &[(u64, Vec<Point>)]) -> HashMap<Point, Vec<u64>> {
+    let mut index: HashMap<Point, Vec<u64>> = HashMap::new();
+    for (value, symbols) in numbers {
+        for &p in symbols {
+            index.entry(p).or_default().push(*value);
+        }
+    }
+    index
+}
+
+fn row_diff(grid: &[Vec<char>], a: usize, b: usize) -> usize {
+    grid[a].iter().zip(&grid[b]).filter(|(x, y)| x != y).count()
+}
+
+fn col_diff(grid: &[Vec<char>], a: usize, b: usize) -> usize {
+    grid.iter().filter(|row| row[a] != row[b]).count()
+}
+
+/// Cell lookup on a (possibly ragged) nested grid; `None` when out of bounds.
+fn grid_cell<T>(grid: &[Vec<T>], pt: Point) -> Option<&T> {
+    if pt.x < 0 || pt.y < 0 {
+        return None;
+    }
+    grid.get(pt.y as usize)?.get(pt.x as usize)
+}
+
+//##################################################################################################
+// Point Sets
+//##################################################################################################
+
+/// Fold the left half over a vertical line `x = axis`; points on the line are dropped.
+pub fn fold_x(points: &HashSet<Point>, axis: i64) -> HashSet<Point> {
+    points
+        .iter()
+        .filter(|p| p.x != axis)
+        .map(|&p| {
+            if p.x > axis {
+                Point::new(axis - (p.x - axis), p.y)
+            } else {
+                p
+            }
+        })
+        .collect()
+}
+
+/// Fold the bottom half up over a horizontal line `y = axis`; points on the line are dropped.
+pub fn fold_y(points: &HashSet<Point>, axis: i64) -> HashSet<Point> {
+    points
+        .iter()
+        .filter(|p| p.y != axis)
+        .map(|&p| {
+            if p.y > axis {
+                Point::new(p.x, axis - (p.y - axis))
+            } else {
+                p
+            }
+        })
+        .collect()
+}
+
+/// Render a point set as `#`/`.` rows covering its bounding box (empty string for no points).
+pub fn render_points(points: &HashSet<Point>) -> String {
+    let (Some(min_x), Some(max_x)) = (
+        points.iter().map(|p| p.x).min(),
+        points.iter().map(|p| p.x).max(),
+    ) else {
+        return String::new();
+    };
+    let min_y = points.iter().map(|p| p.y).min().unwrap_or(0);
+    let max_y = points.iter().map(|p| p.y).max().unwrap_or(0);
+
+    let mut out = String::new();
+    for y in min_y..=max_y {
+        if y > min_y {
+            out.push('\n');
+        }
+        for x in min_x..=max_x {
+            out.push(if points.contains(&Point::new(x, y)) {
+                '#'
+            } else {
+                '.'
+            });
+        }
+    }
+    out
+}
+
+/// The 4x6 capital-letter font AoC draws answers in (letters it never uses are absent).
+const OCR_GLYPHS: &[(char, [&str; 6])] = &[
+    ('A', [".##.", "#..#", "#..#", "####", "#..#", "#..#"]),
+    ('B', ["###.", "#..#", "###.", "#..#", "#..#", "###."]),
+    ('C', [".##.", "#..#", "#...", "#...", "#..#", ".##."]),
+    ('E', ["####", "#...", "###.", "#...", "#...", "####"]),
+    ('F', ["####", "#...", "###.", "#...", "#...", "#..."]),
+    ('G', [".##.", "#..#", "#...", "#.##", "#..#", ".###"]),
+    ('H', ["#..#", "#..#", "####", "#..#", "#..#", "#..#"]),
+    ('I', [".###", "..#.", "..#.", "..#.", "..#.", ".###"]),
+    ('J', ["..##", "...#", "...#", "...#", "#..#", ".##."]),
+    ('K', ["#..#", "#.#.", "##..", "#.#.", "#.#.", "#..#"]),
+    ('L', ["#...", "#...", "#...", "#...", "#...", "####"]),
+    ('O', [".##.", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('P', ["###.", "#..#", "#..#", "###.", "#...", "#..."]),
+    ('R', ["###.", "#..#", "#..#", "###.", "#.#.", "#..#"]),
+    ('S', [".###", "#...", "#...", ".##.", "...#", "###."]),
+    ('U', ["#..#", "#..#", "#..#", "#..#", "#..#", ".##."]),
+    ('Y', ["#...", "#...", ".#.#", "..#.", "..#.", "..#."]),
+    ('Z', ["####", "...#", "..#.", ".#..", "#...", "####"]),
+];
+
+/// Read letters drawn in AoC's 6-pixel-tall font (`true` = lit): the picture is cut into
+/// 5-column cells (4 pixels + 1 gap) and each cell is matched against the glyph table. I and J
+/// leave their cell's first columns blank, so every start up to 3 columns before the first lit
+/// one is tried and the reading with the fewest `?` wins (ties go to the leftmost start, i.e.
+/// the x = 0 boundary for AoC's own output). Only the first 6 rows are read.
+pub fn ocr(grid: &[Vec<bool>]) -> String {
+    let rows = &grid[..grid.len().min(6)];
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    let lit = |x: usize, y: usize| rows.get(y).and_then(|r| r.get(x)).copied().unwrap_or(false);
+    let lit_col = |x: usize| (0..6).any(|y| lit(x, y));
+    let (Some(first), Some(last)) = (
+        (0..width).find(|&x| lit_col(x)),
+        (0..width).rfind(|&x| lit_col(x)),
+    ) else {
+        return String::new();
+    };
+
+    let read = |start: usize| -> String {
+        (start..=last)
+            .step_by(5)
+            .map(|x0| {
+                OCR_GLYPHS
+                    .iter()
+                    .find(|(_, glyph)| {
+                        glyph.iter().enumerate().all(|(y, row)| {
+                            row.bytes()
+                                .enumerate()
+                                .all(|(dx, b)| lit(x0 + dx, y) == (b == b'#'))
+                        })
+                    })
+                    .map_or('?', |&(c, _)| c)
+            })
+            .collect()
+    };
+    (first.saturating_sub(3)..=first)
+        .map(read)
+        .min_by_key(|text| text.matches('?').count())
+        .unwrap_or_default()
+}
+
+/// Render a char grid row by row (newline separated), drawing `marker` on every `overlay` cell;
+/// handy for eyeballing the path a search took. Overlay points outside the grid are ignored.
+pub fn render_grid_with_overlay(
+    grid: &[Vec<char>],
+    overlay: &HashSet<Point>,
+    marker: char,
+) -> String {
+    let mut out = String::new();
+    for (y, row) in grid.iter().enumerate() {
+        if y > 0 {
+            out.push('\n');
+        }
+        for (x, &c) in row.iter().enumerate() {
+            let on_path = overlay.contains(&Point::new(x as i64, y as i64));
+            out.push(if on_path { marker } else { c });
+        }
+    }
+    out
+}
+
+/// Assign every cell of the inclusive box `area = (min, max)` to the index of its Manhattan-nearest
+/// point, or `None` when two or more points tie.
+pub fn nearest_label(points: &[Point], area: (Point, Point)) -> HashMap<Point, Option<usize>> {
+    let (min, max) = area;
+    let mut labels = HashMap::new();
+    for y in min.y..=max.y {
+        for x in min.x..=max.x {
+            let cell = Point::new(x, y);
+            let mut best: Option<(i64, Option<usize>)> = None;
+            for (i, &p) in points.iter().enumerate() {
+                let d = p.manhattan(cell);
+                best = match best {
+                    Some((bd, _)) if d == bd => Some((d, None)),
+                    Some((bd, label)) if d > bd => Some((bd, label)),
+                    _ => Some((d, Some(i))),
+                };
+            }
+            labels.insert(cell, best.and_then(|(_, label)| label));
+        }
+    }
+    labels
+}
+
+/// Labels owning a cell on the edge of `area`; their regions extend forever outside the box.
+pub fn infinite_labels(
+    labels: &HashMap<Point, Option<usize>>,
+    area: (Point, Point),
+) -> HashSet<usize> {
+    let (min, max) = area;
+    labels
+        .iter()
+        .filter(|(p, _)| p.x == min.x || p.x == max.x || p.y == min.y || p.y == max.y)
+        .filter_map(|(_, &label)| label)
+        .collect()
+}
+
+/// Manhattan-nearest point to `target`; ties go to the earliest point in `points`.
+pub fn closest_to(points: &[Point], target: Point) -> Option<Point> {
+    // `min_by_key` keeps the first minimum.
+    points.iter().copied().min_by_key(|p| p.manhattan(target))
+}
+
+/// Manhattan-farthest point from `target`; ties go to the earliest point in `points`.
+pub fn farthest_from(points: &[Point], target: Point) -> Option<Point> {
+    // `max_by_key` keeps the last maximum, so compare on the negated distance instead.
+    points
+        .iter()
+        .copied()
+        .min_by_key(|p| std::cmp::Reverse(p.manhattan(target)))
+}
+
+/// Largest Manhattan distance between any two points (0 for fewer than two), in `O(n)` via the
+/// rotated `x + y` / `x - y` extents.
+pub fn pairwise_max_distance(points: &[Point]) -> i64 {
+    let extent = |f: fn(&Point) -> i64| {
+        let vals = points.iter().map(f);
+        vals.clone().max().unwrap_or(0) - vals.min().unwrap_or(0)
+    };
+    extent(|p| p.x + p.y).max(extent(|p| p.x - p.y))
+}
+
+/// Rows and columns (`(empty_rows, empty_cols)`, ascending) inside the points' bounding box that
+/// hold no point; rows/columns outside the box never change distances, so they are not listed.
+pub fn empty_rows_cols(points: &[Point]) -> (Vec<i64>, Vec<i64>) {
+    let empty_between = |coords: HashSet<i64>| -> Vec<i64> {
+        match (coords.iter().min(), coords.iter().max()) {
+            (Some(&lo), Some(&hi)) => (lo..=hi).filter(|c| !coords.contains(c)).collect(),
+            _ => Vec::new(),
+        }
+    };
+    (
+        empty_between(points.iter().map(|p| p.y).collect()),
+        empty_between(points.iter().map(|p| p.x).collect()),
+    )
+}
+
+/// Grow every empty row/column to `factor` copies: each point moves by `(factor - 1)` times the
+/// number of empty rows (above it) and columns (left of it). `factor = 2` doubles them.
+pub fn expand_empty(
+    points: &[Point],
+    empty_rows: &[i64],
+    empty_cols: &[i64],
+    factor: i64,
+) -> Vec<Point> {
+    let sorted = |v: &[i64]| {
+        let mut v = v.to_vec();
+        v.sort_unstable();
+        v
+    };
+    let (rows, cols) = (sorted(empty_rows), sorted(empty_cols));
+    let before = |v: &[i64], c: i64| v.partition_point(|&e| e < c) as i64;
+    points
+        .iter()
+        .map(|p| {
+            Point::new(
+                p.x + (factor - 1) * before(&cols, p.x),
+                p.y + (factor - 1) * before(&rows, p.y),
+            )
+        })
+        .collect()
+}
+
+/// Sum of Manhattan distances over all unordered pairs, in `O(n log n)`: per axis, the sorted
+/// `k`-th coordinate contributes `c * k - (sum of the k smaller ones)`.
+pub fn sum_pairwise_manhattan(points: &[Point]) -> i64 {
+    let axis = |mut coords: Vec<i64>| {
+        coords.sort_unstable();
+        let mut prefix = 0;
+        let mut total = 0;
+        for (k, &c) in coords.iter().enumerate() {
+            total += c * k as i64 - prefix;
+            prefix += c;
+        }
+        total
+    };
+    axis(points.iter().map(|p| p.x).collect()) + axis(points.iter().map(|p| p.y).collect())
+}
+
+//##################################################################################################
+// Image Output (feature = "render")
+//##################################################################################################
+
+/// Write a byte grid as a binary PPM (P6) image, coloring each cell with `palette[value]`.
+/// Rows must all be the same width and every value must have a palette entry.
+#[cfg(feature = "render")]
+pub fn render_ppm(
+    grid: &[Vec<u8>],
+    palette: &[(u8, u8, u8)],
+    path: impl AsRef<Path>,
+) -> Result<()> {
+    let path = path.as_ref();
+    let width = grid.first().map_or(0, Vec::len);
+    let mut data = format!("P6\n{width} {}\n255\n", grid.len()).into_bytes();
+    for (y, row) in grid.iter().enumerate() {
+        if row.len() != width {
+            return Err(anyhow!(
+                "render_ppm: row {y} has width {}, expected {width}",
+                row.len()
+            ));
+        }
+        for (x, &v) in row.iter().enumerate() {
+            let &(r, g, b) = palette
+                .get(v as usize)
+                .ok_or_else(|| anyhow!("render_ppm: no palette entry for {v} at ({x}, {y})"))?;
+            data.extend([r, g, b]);
+        }
+    }
+    fs::write(path, data).with_context(|| format!("Failed to write image: {}", path.display()))
+}
+
+//##################################################################################################
+// Segment Geometry
+//##################################################################################################
+
+/// Intersection of the closed integer segments `a1-a2` and `b1-b2` (any slope, endpoints included).
+/// Returns `None` when they miss or when the crossing is not at integer coordinates.
+/// Collinear overlap is reported as the overlap endpoint nearest `a1`; use
+/// `manhattan_closest_intersection` if the whole overlap matters.
+pub fn segment_intersection(a1: Point, a2: Point, b1: Point, b2: Point) -> Option<Point> {
+    let da = (a2.x - a1.x, a2.y - a1.y);
+    let db = (b2.x - b1.x, b2.y - b1.y);
+    let ab = (b1.x - a1.x, b1.y - a1.y);
+    let mut denom = cross(da, db);
+    if denom == 0 {
+        return segment_overlap(a1, a2, b1, b2).map(|(near, _)| near);
+    }
+
+    let (mut t, mut u) = (cross(ab, db), cross(ab, da));
+    if denom < 0 {
+        (denom, t, u) = (-denom, -t, -u);
+    }
+    if !(0..=denom).contains(&t) || !(0..=denom).contains(&u) {
+        return None;
+    }
+    let x = a1.x as i128 * denom + t * da.0 as i128;
+    let y = a1.y as i128 * denom + t * da.1 as i128;
+    if x % denom != 0 || y % denom != 0 {
+        return None;
+    }
+    Some(Point::new((x / denom) as i64, (y / denom) as i64))
+}
+
+/// Crossing of two wires (polylines given by their corner points) nearest the origin by Manhattan
+/// distance, ignoring the origin itself. Collinear overlaps are searched along their length.
+pub fn manhattan_closest_intersection(wire_a: &[Point], wire_b: &[Point]) -> Option<Point> {
+    let origin = Point::new(0, 0);
+    let mut candidates = Vec::new();
+    for a in wire_a.windows(2) {
+        for b in wire_b.windows(2) {
+            match segment_overlap(a[0], a[1], b[0], b[1]) {
+                Some((p, q)) => {
+                    candidates.extend([p, q]);
+                    // The closest overlap points hug the origin's projection onto the overlap.
+                    for d in -1..=1 {
+                        if p.x == q.x {
+                            candidates.push(Point::new(p.x, d.clamp(p.y.min(q.y), p.y.max(q.y))));
+                        } else if p.y == q.y {
+                            candidates.push(Point::new(d.clamp(p.x.min(q.x), p.x.max(q.x)), p.y));
+                        }
+                    }
+                }
+                None => candidates.extend(segment_intersection(a[0], a[1], b[0], b[1])),
+            }
+        }
+    }
+    candidates
+        .into_iter()
+        .filter(|&p| p != origin)
+        .min_by_key(|&p| (p.manhattan(origin), p))
+}
+
+fn cross(a: (i64, i64), b: (i64, i64)) -> i128 {
+    a.0 as i128 * b.1 as i128 - a.1 as i128 * b.0 as i128
+}
+
+/// Overlap of two collinear segments as `(endpoint nearest a1, far endpoint)`; `None` if the
+/// segments are not collinear or don't touch.
+fn segment_overlap(a1: Point, a2: Point, b1: Point, b2: Point) -> Option<(Point, Point)> {
+    let da = (a2.x - a1.x, a2.y - a1.y);
+    let db = (b2.x - b1.x, b2.y - b1.y);
+    if da == (0, 0) && db == (0, 0) {
+        return (a1 == b1).then_some((a1, a1));
+    }
+    let on_line = |p: Point, o: Point, d: (i64, i64)| cross(d, (p.x - o.x, p.y - o.y)) == 0;
+    if cross(da, db) != 0 || !on_line(b1, a1, da) || !on_line(a1, b1, db) {
+        return None;
+    }
+    // Parametrize along whichever segment is non-degenerate; overlap endpoints are always among
+    // the four input endpoints.
+    let (o, d) = if da != (0, 0) { (a1, da) } else { (b1, db) };
+    let key = |p: Point| (p.x - o.x) as i128 * d.0 as i128 + (p.y - o.y) as i128 * d.1 as i128;
+    let (a_lo, a_hi) = if key(a1) <= key(a2) {
+        (a1, a2)
+    } else {
+        (a2, a1)
+    };
+    let (b_lo, b_hi) = if key(b1) <= key(b2) {
+        (b1, b2)
+    } else {
+        (b2, b1)
+    };
+    let lo = if key(a_lo) >= key(b_lo) { a_lo } else { b_lo };
+    let hi = if key(a_hi) <= key(b_hi) { a_hi } else { b_hi };
+    if key(lo) > key(hi) {
+        return None;
+    }
+    if lo.manhattan(a1) <= hi.manhattan(a1) {
+        Some((lo, hi))
+    } else {
+        Some((hi, lo))
+    }
+}
+
+//##################################################################################################
+// Combinatorics
+//##################################################################################################
+
+/// All orderings of `items` (Heap's algorithm); yields `n!` owned vectors.
+pub fn permutations<T: Clone>(items: &[T]) -> impl Iterator<Item = Vec<T>> {
+    let mut items = items.to_vec();
+    let mut c = vec![0usize; items.len()];
+    let mut i = 1;
+    let mut first = true;
+
+    std::iter::from_fn(move || {
+        if first {
+            first = false;
+            return Some(items.clone());
+        }
+        while i < items.len() {
+            if c[i] < i {
+                if i % 2 == 0 {
+                    items.swap(0, i);
+                } else {
+                    items.swap(c[i], i);
+                }
+                c[i] += 1;
+                i = 1;
+                return Some(items.clone());
+            }
+            c[i] = 0;
+            i += 1;
+        }
+        None
+    })
+}
+
+/// All `k`-element combinations of `items` in lexicographic index order.
+pub fn combinations<T: Clone>(items: &[T], k: usize) -> impl Iterator<Item = Vec<T>> + '_ {
+    let n = items.len();
+    let mut idx: Vec<usize> = (0..k).collect();
+    let mut done = k > n;
+
+    std::iter::from_fn(move || {
+        if done {
+            return None;
+        }
+        let out = idx.iter().map(|&i| items[i].clone()).collect();
+
+        // Advance the rightmost index that still has room to move.
+        match (0..k).rev().find(|&i| idx[i] != i + n - k) {
+            Some(i) => {
+                idx[i] += 1;
+                for j in i + 1..k {
+                    idx[j] = idx[j - 1] + 1;
+                }
+            }
+            None => done = true,
+        }
+        Some(out)
+    })
+}
+
+/// Shortest route visiting every node of the distance matrix `dist` (Held-Karp bitmask DP,
+/// `O(2^n * n^2)`). With `return_to_start` the route is a closed tour (started from node 0,
+/// which loses nothing on a cycle); otherwise it is an open path that may start and end
+/// anywhere. 0 for fewer than two nodes; an error for a non-square matrix or more than
+/// `TSP_MAX_NODES` nodes, where the DP table stops fitting comfortably in memory.
+pub fn tsp_min(dist: &[Vec<u64>], return_to_start: bool) -> Result<u64> {
+    held_karp(dist, return_to_start, u64::min)
+}
+
+/// Longest route under the same rules as `tsp_min` (the "longest trip" part).
+pub fn tsp_max(dist: &[Vec<u64>], return_to_start: bool) -> Result<u64> {
+    held_karp(dist, return_to_start, u64::max)
+}
+
+/// Node limit for `tsp_min` / `tsp_max`: the DP table holds `2^n * n` entries (~16 MB at 16).
+pub const TSP_MAX_NODES: usize = 16;
+
+fn held_karp(dist: &[Vec<u64>], closed: bool, pick: fn(u64, u64) -> u64) -> Result<u64> {
+    let n = dist.len();
+    if n > TSP_MAX_NODES {
+        return Err(anyhow!(
+            "{n} nodes exceeds the Held-Karp limit of {TSP_MAX_NODES}"
+        ));
+    }
+    if let Some(row) = dist.iter().position(|row| row.len() != n) {
+        return Err(anyhow!(
+            "Distance matrix row {row} does not have {n} entries"
+        ));
+    }
+    if n < 2 {
+        return Ok(0);
+    }
+    let full = (1usize << n) - 1;
+
+    // best[mask][last]: best cost of a path covering `mask` and ending at `last`.
+    let mut best: Vec<Vec<Option<u64>>> = vec![vec![None; n]; 1 << n];
+    let starts = if closed { 0..1 } else { 0..n };
+    for s in starts {
+        best[1 << s][s] = Some(0);
+    }
+    for mask in 1..=full {
+        for last in 0..n {
+            let Some(cost) = best[mask][last] else {
+                continue;
+            };
+            for next in (0..n).filter(|&j| mask & (1 << j) == 0) {
+                let slot = &mut best[mask | (1 << next)][next];
+                let cand = cost + dist[last][next];
+                *slot = Some(slot.map_or(cand, |cur| pick(cur, cand)));
+            }
+        }
+    }
+
+    Ok((0..n)
+        .filter_map(|last| {
+            let cost = best[full][last]?;
+            Some(if closed { cost + dist[last][0] } else { cost })
+        })
+        .reduce(pick)
+        .unwrap_or(0))
+}
+
+//##################################################################################################
+// Sequences
+//##################################################################################################
+
+/// Run-length encode consecutive equal items as `(item, run_length)`.
+pub fn run_length_encode<T: PartialEq + Clone>(items: &[T]) -> Vec<(T, usize)> {
+    let mut runs: Vec<(T, usize)> = Vec::new();
+    for item in items {
+        match runs.last_mut() {
+            Some((last, n)) if last == item => *n += 1,
+            _ => runs.push((item.clone(), 1)),
+        }
+    }
+    runs
+}
+
+/// Expand `(item, run_length)` pairs back into a flat sequence.
+pub fn run_length_decode<T: Clone>(runs: &[(T, usize)]) -> Vec<T> {
+    runs.iter()
+        .flat_map(|(item, n)| std::iter::repeat(item.clone()).take(*n))
+        .collect()
+}
+
+/// Split `items` into maximal runs of adjacent elements whose `key` is equal.
+pub fn group_by_key<T, K: PartialEq, F: Fn(&T) -> K>(items: &[T], key: F) -> Vec<Vec<&T>> {
+    let mut groups: Vec<(K, Vec<&T>)> = Vec::new();
+    for item in items {
+        let k = key(item);
+        match groups.last_mut() {
+            Some((last, group)) if *last == k => group.push(item),
+            _ => groups.push((k, vec![item])),
+        }
+    }
+    groups.into_iter().map(|(_, group)| group).collect()
+}
+
+/// The `n` largest items in descending order, via a bounded min-heap (`O(m log n)`).
+pub fn top_n<T: Ord + Clone>(items: impl IntoIterator<Item = T>, n: usize) -> Vec<T> {
+    use std::cmp::Reverse;
+    use std::collections::BinaryHeap;
+
+    if n == 0 {
+        return Vec::new();
+    }
+    let mut heap: BinaryHeap<Reverse<T>> = BinaryHeap::with_capacity(n + 1);
+    for item in items {
+        if heap.len() < n {
+            heap.push(Reverse(item));
+        } else if heap.peek().map_or(false, |Reverse(min)| item > *min) {
+            heap.pop();
+            heap.push(Reverse(item));
+        }
+    }
+    // Ascending order of `Reverse` is descending order of the items.
+    heap.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse(v)| v)
+        .collect()
+}
+
+/// Sum of the `n` largest items (e.g. the top three calorie totals).
+pub fn sum_top_n<T: Ord + Clone + std::iter::Sum>(
+    items: impl IntoIterator<Item = T>,
+    n: usize,
+) -> T {
+    top_n(items, n).into_iter().sum()
+}
+
+/// One look-and-say step: `"1211"` -> `"111221"`.
+pub fn look_and_say(seed: &str) -> String {
+    let chars: Vec<char> = seed.trim().chars().collect();
+    run_length_encode(&chars)
+        .into_iter()
+        .map(|(c, n)| format!("{n}{c}"))
+        .collect()
+}
+
+//##################################################################################################
+// Data Structures
+//##################################################################################################
+
+/// Circular list with a movable "current" element, backed by a `VecDeque` so rotations and
+/// inserts next to the cursor are O(|offset|) instead of the O(n) shifting of `Vec::insert`.
+/// Positive offsets move clockwise, negative ones counter-clockwise.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CircularList<T> {
+    // Front of the deque is the current element.
+    items: VecDeque<T>,
+}
+
+impl<T> CircularList<T> {
+    pub fn new() -> Self {
+        Self {
+            items: VecDeque::new(),
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// The element under the cursor.
+    pub fn current(&self) -> Option<&T> {
+        self.items.front()
+    }
+
+    /// Move the cursor `n` steps (clockwise when positive).
+    pub fn rotate(&mut self, n: i64) {
+        if self.items.is_empty() {
+            return;
+        }
+        let k = n.rem_euclid(self.items.len() as i64) as usize;
+        self.items.rotate_left(k);
+    }
+
+    /// Insert `value` right after the element `offset` steps away; the new value becomes current.
+    pub fn insert_after(&mut self, offset: i64, value: T) {
+        // Bring the anchor's successor to the front; the anchor then sits at the back, i.e.
+        // directly before the pushed value in circular order.
+        self.rotate(offset + 1);
+        self.items.push_front(value);
+    }
+
+    /// Remove the element right after the one `offset` steps away (the slot `insert_after` fills);
+    /// its clockwise successor becomes current.
+    pub fn remove_after(&mut self, offset: i64) -> Option<T> {
+        self.remove_at(offset + 1)
+    }
+
+    /// Remove the element `offset` steps away (`0` = current); its clockwise successor becomes
+    /// current.
+    pub fn remove_at(&mut self, offset: i64) -> Option<T> {
+        self.rotate(offset);
+        self.items.pop_front()
+    }
+
+    /// Iterate clockwise starting at the current element.
+    pub fn iter(&self) -> impl Iterator<Item = &T> {
+        self.items.iter()
+    }
+}
+
+impl<T> FromIterator<T> for CircularList<T> {
+    /// The first item becomes current.
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self {
+            items: iter.into_iter().collect(),
+        }
+    }
+}
+
+/// Set of integers stored as sorted, merged inclusive ranges (touching ranges coalesce).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct RangeSet {
+    ranges: Vec<(i64, i64)>,
+}
+
+impl RangeSet {
+    /// Merge `ranges` (inclusive `(start, end)`, `start <= end`) into a set.
+    pub fn new(ranges: impl IntoIterator<Item = (i64, i64)>) -> Self {
+        let mut sorted: Vec<(i64, i64)> = ranges.into_iter().collect();
+        sorted.sort_unstable();
+        let mut merged: Vec<(i64, i64)> = Vec::with_capacity(sorted.len());
+        for (a, b) in sorted {
+            match merged.last_mut() {
+                Some(last) if a <= last.1.saturating_add(1) => last.1 = last.1.max(b),
+                _ => merged.push((a, b)),
+            }
+        }
+        Self { ranges: merged }
+    }
+
+    /// The merged ranges in ascending order.
+    pub fn ranges(&self) -> &[(i64, i64)] {
+        &self.ranges
+    }
+
+    pub fn contains(&self, x: i64) -> bool {
+        let idx = self.ranges.partition_point(|&(a, _)| a <= x);
+        idx > 0 && x <= self.ranges[idx - 1].1
+    }
+
+    /// Number of integers covered.
+    pub fn covered_count(&self) -> u64 {
+        self.ranges.iter().map(|&(a, b)| a.abs_diff(b) + 1).sum()
+    }
+
+    /// Every covered integer in ascending order, produced lazily. Meant for small totals: a
+    /// billion-wide range really will be walked one value at a time (check `covered_count`).
+    pub fn iter(&self) -> impl Iterator<Item = i64> + '_ {
+        self.ranges.iter().flat_map(|&(a, b)| a..=b)
+    }
+}
+
+impl FromIterator<(i64, i64)> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = (i64, i64)>>(iter: I) -> Self {
+        Self::new(iter)
+    }
+}
+
+/// Point set over a fixed `width x height` box, stored as one bit per cell. Much smaller and faster
+/// than `HashSet<Point>` for dense flood-fill / falling-sand states. Inserting outside the box
+/// panics; `contains` just returns `false` there.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GridSet {
+    width: usize,
+    height: usize,
+    bits: Vec<u64>,
+    len: usize,
+}
+
+impl GridSet {
+    /// Empty set covering `(0, 0)..(width, height)`.
+    pub fn new(width: usize, height: usize) -> Self {
+        Self {
+            width,
+            height,
+            bits: vec![0; (width * height + 63) / 64],
+            len: 0,
+        }
+    }
+
+    fn index(&self, pt: Point) -> Option<usize> {
+        (pt.x >= 0 && pt.y >= 0 && (pt.x as usize) < self.width && (pt.y as usize) < self.height)
+            .then(|| pt.y as usize * self.width + pt.x as usize)
+    }
+
+    /// Add a point; returns `true` if it was not already present.
+    pub fn insert(&mut self, pt: Point) -> bool {
+        let i = self
+            .index(pt)
+            .unwrap_or_else(|| panic!("Point {pt} outside GridSet bounds"));
+        let (word, mask) = (i / 64, 1u64 << (i % 64));
+        let fresh = self.bits[word] & mask == 0;
+        self.bits[word] |= mask;
+        self.len += fresh as usize;
+        fresh
+    }
+
+    /// Remove a point; returns `true` if it was present.
+    pub fn remove(&mut self, pt: Point) -> bool {
+        let Some(i) = self.index(pt) else {
+            return false;
+        };
+        let (word, mask) = (i / 64, 1u64 << (i % 64));
+        let present = self.bits[word] & mask != 0;
+        self.bits[word] &= !mask;
+        self.len -= present as usize;
+        present
+    }
+
+    pub fn contains(&self, pt: Point) -> bool {
+        self.index(pt)
+            .map_or(false, |i| self.bits[i / 64] & (1u64 << (i % 64)) != 0)
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Members in row-major order.
+    pub fn iter(&self) -> impl Iterator<Item = Point> + '_ {
+        self.bits.iter().enumerate().flat_map(move |(w, &word)| {
+            (0..64)
+                .filter(move |b| word & (1u64 << b) != 0)
+                .map(move |b| {
+                    let i = w * 64 + b;
+                    Point::new((i % self.width) as i64, (i / self.width) as i64)
+                })
+        })
+    }
+}
+
+/// Prefix tree over the bytes of stored strings.
+#[derive(Debug, Clone)]
+pub struct Trie {
+    // nodes[0] is the root; each node maps a byte to a child index and flags word ends.
+    nodes: Vec<(HashMap<u8, usize>, bool)>,
+}
+
+impl Default for Trie {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Trie {
+    pub fn new() -> Self {
+        Self {
+            nodes: vec![(HashMap::new(), false)],
+        }
+    }
+
+    /// Store a word.
+    pub fn insert(&mut self, word: &str) {
+        let mut node = 0;
+        for b in word.bytes() {
+            node = match self.nodes[node].0.get(&b) {
+                Some(&next) => next,
+                None => {
+                    self.nodes.push((HashMap::new(), false));
+                    let next = self.nodes.len() - 1;
+                    self.nodes[node].0.insert(b, next);
+                    next
+                }
+            };
+        }
+        self.nodes[node].1 = true;
+    }
+
+    /// Whether `word` was stored exactly.
+    pub fn contains(&self, word: &str) -> bool {
+        let mut node = 0;
+        for b in word.bytes() {
+            match self.nodes[node].0.get(&b) {
+                Some(&next) => node = next,
+                None => return false,
+            }
+        }
+        self.nodes[node].1
+    }
+
+    /// Byte lengths of stored words that prefix `s`, shortest first (so `&s[len..]` is the rest).
+    pub fn prefixes_of(&self, s: &str) -> Vec<usize> {
+        let mut out = Vec::new();
+        let mut node = 0;
+        for (i, b) in s.bytes().enumerate() {
+            match self.nodes[node].0.get(&b) {
+                Some(&next) => node = next,
+                None => break,
+            }
+            if self.nodes[node].1 {
+                out.push(i + 1);
+            }
+        }
+        out
+    }
+}
+
+impl<'a> FromIterator<&'a str> for Trie {
+    fn from_iter<I: IntoIterator<Item = &'a str>>(iter: I) -> Self {
+        let mut trie = Trie::new();
+        for word in iter {
+            trie.insert(word);
+        }
+        trie
+    }
+}
+
+/// Union-find over `0..n` with path halving and union by size.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DisjointSet {
+    parent: Vec<usize>,
+    size: Vec<usize>,
+}
+
+impl DisjointSet {
+    /// `n` singleton sets.
+    pub fn new(n: usize) -> Self {
+        Self {
+            parent: (0..n).collect(),
+            size: vec![1; n],
+        }
+    }
+
+    /// Representative of the set containing `x`.
+    pub fn find(&mut self, mut x: usize) -> usize {
+        while self.parent[x] != x {
+            self.parent[x] = self.parent[self.parent[x]];
+            x = self.parent[x];
+        }
+        x
+    }
+
+    /// Merge the sets of `a` and `b`; false if they were already joined.
+    pub fn union(&mut self, a: usize, b: usize) -> bool {
+        let (mut ra, mut rb) = (self.find(a), self.find(b));
+        if ra == rb {
+            return false;
+        }
+        if self.size[ra] < self.size[rb] {
+            std::mem::swap(&mut ra, &mut rb);
+        }
+        self.parent[rb] = ra;
+        self.size[ra] += self.size[rb];
+        true
+    }
+
+    /// Size of the set containing `x`.
+    pub fn set_size(&mut self, x: usize) -> usize {
+        let r = self.find(x);
+        self.size[r]
+    }
+}
+
+//##################################################################################################
+// Text Transforms
+//##################################################################################################
+
+/// Caesar-shift ASCII letters forward by `n`, keeping case; everything else is left alone.
+pub fn rot_alpha(s: &str, n: u32) -> String {
+    let shift = (n % 26) as u8;
+    s.chars()
+        .map(|c| match c {
+            'a'..='z' => ((c as u8 - b'a' + shift) % 26 + b'a') as char,
+            'A'..='Z' => ((c as u8 - b'A' + shift) % 26 + b'A') as char,
+            _ => c,
+        })
+        .collect()
+}
 
-    while let Some((Reverse(d), node)) = heap.pop() {
-        if d != dist[&node] {
-            continue; // stale entry
+/// The `top` most frequent ASCII letters of `s` (case-insensitive, returned lowercase), most
+/// common first with ties broken alphabetically; the room-name checksum.
+pub fn checksum_letters(s: &str, top: usize) -> String {
+    let freq = counts(
+        s.chars()
+            .filter(char::is_ascii_alphabetic)
+            .map(|c| c.to_ascii_lowercase()),
+    );
+    let mut letters: Vec<(char, usize)> = freq.into_iter().collect();
+    letters.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    letters.into_iter().take(top).map(|(c, _)| c).collect()
+}
+
+/// Rabin-Karp prefix hashes of a byte string (mod the Mersenne prime `2^61 - 1`), giving `O(1)`
+/// hashes of any substring. Equal hashes are only a hint: the search helpers confirm every
+/// candidate byte for byte, so collisions never produce wrong matches.
+#[derive(Debug, Clone)]
+pub struct RollingHash {
+    prefix: Vec<u64>,
+    pow: Vec<u64>,
+}
+
+impl RollingHash {
+    const MOD: u64 = (1 << 61) - 1;
+    const BASE: u64 = 911_382_323;
+
+    fn mul(a: u64, b: u64) -> u64 {
+        (a as u128 * b as u128 % Self::MOD as u128) as u64
+    }
+
+    pub fn new(text: &[u8]) -> Self {
+        let mut prefix = Vec::with_capacity(text.len() + 1);
+        let mut pow = Vec::with_capacity(text.len() + 1);
+        prefix.push(0);
+        pow.push(1);
+        for (i, &b) in text.iter().enumerate() {
+            prefix.push((Self::mul(prefix[i], Self::BASE) + b as u64 + 1) % Self::MOD);
+            pow.push(Self::mul(pow[i], Self::BASE));
         }
-        for (nxt, w) in neighbors(node) {
-            let nd = d + w;
-            let entry = dist.entry(nxt).or_insert(u64::MAX);
-            if nd < *entry {
-                *entry = nd;
-                heap.push((Reverse(nd), nxt));
+        Self { prefix, pow }
+    }
+
+    /// Hash of `text[range]`; panics if the range is out of bounds.
+    pub fn hash(&self, range: std::ops::Range<usize>) -> u64 {
+        let sub = Self::mul(self.prefix[range.start], self.pow[range.len()]);
+        (self.prefix[range.end] + Self::MOD - sub) % Self::MOD
+    }
+
+    /// First index where `needle` occurs in `haystack` (`Some(0)` for an empty needle).
+    pub fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+        Self::matches(haystack, needle).next()
+    }
+
+    /// Every index where `needle` occurs, overlapping matches included.
+    pub fn find_all(haystack: &[u8], needle: &[u8]) -> Vec<usize> {
+        Self::matches(haystack, needle).collect()
+    }
+
+    fn matches<'a>(haystack: &'a [u8], needle: &'a [u8]) -> impl Iterator<Item = usize> + 'a {
+        let n = needle.len();
+        let hay = RollingHash::new(haystack);
+        let target = RollingHash::new(needle).hash(0..n);
+        (0..(haystack.len() + 1).saturating_sub(n))
+            .filter(move |&i| hay.hash(i..i + n) == target && &haystack[i..i + n] == needle)
+    }
+}
+
+/// Longest substring occurring at least twice (occurrences may overlap); the earliest such
+/// substring on ties, empty if no byte repeats. Binary search on the length with `RollingHash`,
+/// `O(n log n)` expected.
+pub fn longest_repeated_substring(text: &[u8]) -> &[u8] {
+    let hashes = RollingHash::new(text);
+    // Start of the first window of length `len` that appears again later, if any.
+    let repeated_at = |len: usize| -> Option<usize> {
+        let mut seen: HashMap<u64, Vec<usize>> = HashMap::new();
+        let mut best: Option<usize> = None;
+        for i in 0..=text.len() - len {
+            let starts = seen.entry(hashes.hash(i..i + len)).or_default();
+            if let Some(&j) = starts
+                .iter()
+                .find(|&&j| text[j..j + len] == text[i..i + len])
+            {
+                best = Some(best.map_or(j, |b| b.min(j)));
+            } else {
+                starts.push(i);
             }
         }
+        best
+    };
+
+    let (mut lo, mut hi, mut found) = (1, text.len().saturating_sub(1), None);
+    while lo <= hi {
+        let mid = (lo + hi) / 2;
+        match repeated_at(mid) {
+            Some(start) => {
+                found = Some((start, mid));
+                lo = mid + 1;
+            }
+            None => hi = mid - 1,
+        }
     }
+    found.map_or(&text[..0], |(start, len)| &text[start..start + len])
+}
 
-    dist
+//##################################################################################################
+// Puzzle Mechanics
+//##################################################################################################
+
+/// State after applying `step` `n` times to `initial`. Every state is remembered, so once one
+/// repeats the remaining full loops are skipped and the answer is read from the history; the
+/// billion-iteration puzzles then cost only prefix + one cycle of steps.
+pub fn run_n_steps<S, F>(initial: S, mut step: F, n: usize) -> S
+where
+    S: Eq + std::hash::Hash + Clone,
+    F: FnMut(&S) -> S,
+{
+    let mut seen: HashMap<S, usize> = HashMap::new();
+    let mut history: Vec<S> = Vec::new();
+    let mut state = initial;
+    for i in 0..n {
+        if let Some(&start) = seen.get(&state) {
+            let cycle = i - start;
+            return history[start + (n - start) % cycle].clone();
+        }
+        seen.insert(state.clone(), i);
+        history.push(state.clone());
+        state = step(&state);
+    }
+    state
 }
 
-/// Transpose a rectangular matrix (allocates a new Vec<Vec<T>>); panics if rows are ragged.
-pub fn transpose<T: Clone>(grid: &[Vec<T>]) -> Vec<Vec<T>> {
-    if grid.is_empty() {
-        return Vec::new();
+/// Knot-tying rounds over a circular list `0..size`: for each length, reverse that many elements
+/// from the cursor, then advance by `length + skip` and bump `skip`. Position and skip carry
+/// across rounds. Returns the resulting (sparse) list; a length longer than the list is an error
+/// (the puzzle calls it invalid), which also covers any non-zero length on an empty list.
+pub fn knot_rounds(size: usize, lengths: &[usize], rounds: usize) -> Result<Vec<usize>> {
+    if let Some(&len) = lengths.iter().find(|&&len| len > size) {
+        return Err(anyhow!("Knot length {len} exceeds list size {size}"));
     }
-    let rows = grid.len();
-    let cols = grid[0].len();
-    let mut out = vec![vec![grid[0][0].clone(); rows]; cols];
-    for r in 0..rows {
-        for c in 0..cols {
-            out[c][r] = grid[r][c].clone();
+    let mut list: Vec<usize> = (0..size).collect();
+    if size == 0 {
+        return Ok(list);
+    }
+    let (mut pos, mut skip) = (0usize, 0usize);
+    for _ in 0..rounds {
+        for &len in lengths {
+            for i in 0..len / 2 {
+                list.swap((pos + i) % size, (pos + len - 1 - i) % size);
+            }
+            pos = (pos + len + skip) % size;
+            skip += 1;
         }
     }
-    out
+    Ok(list)
+}
+
+/// Full knot hash: bytes of `input` plus the standard suffix, 64 rounds, XOR-folded into 16
+/// bytes and rendered as 32 lowercase hex chars.
+pub fn knot_hash(input: &str) -> String {
+    let lengths: Vec<usize> = input
+        .trim()
+        .bytes()
+        .chain([17, 31, 73, 47, 23])
+        .map(usize::from)
+        .collect();
+    knot_rounds(256, &lengths, 64)
+        .expect("byte lengths never exceed 256")
+        .chunks(16)
+        .map(|block| format!("{:02x}", block.iter().fold(0, |acc, &v| acc ^ v)))
+        .collect()
+}
+
+/// Rotate a waypoint offset around the origin by `quarter_turns` 90° steps (positive = clockwise).
+/// Uses the grid convention of y growing downward, so east/north `(10, -4)` turns right to `(4, 10)`.
+pub fn rotate_waypoint(wp: Point, quarter_turns: i64) -> Point {
+    match quarter_turns.rem_euclid(4) {
+        0 => wp,
+        1 => Point::new(-wp.y, wp.x),
+        2 => Point::new(-wp.x, -wp.y),
+        _ => Point::new(wp.y, -wp.x),
+    }
+}
+
+/// Ship navigation over `(action, value)` commands: `N`/`S`/`E`/`W` move, `L`/`R` turn by a
+/// multiple of 90 degrees, `F` moves forward. With a waypoint set, `NSEW` and turns act on the
+/// waypoint and `F` moves the ship toward it `value` times.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Navigator {
+    pub pos: Point,
+    pub heading: Dir4,
+    pub waypoint: Option<Point>,
+}
+
+impl Default for Navigator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Navigator {
+    /// Ship at the origin facing east, moving itself.
+    pub fn new() -> Self {
+        Self {
+            pos: Point::new(0, 0),
+            heading: Dir4::Right,
+            waypoint: None,
+        }
+    }
+
+    /// Ship at the origin steering by a waypoint offset.
+    pub fn with_waypoint(waypoint: Point) -> Self {
+        Self {
+            waypoint: Some(waypoint),
+            ..Self::new()
+        }
+    }
+
+    /// Apply a single command.
+    pub fn apply(&mut self, action: char, value: i64) -> Result<()> {
+        let dir = match action {
+            'N' => Some(Dir4::Up),
+            'S' => Some(Dir4::Down),
+            'E' => Some(Dir4::Right),
+            'W' => Some(Dir4::Left),
+            _ => None,
+        };
+        if let Some(dir) = dir {
+            let d = dir.delta();
+            let target = self.waypoint.as_mut().unwrap_or(&mut self.pos);
+            *target = Point::new(target.x + d.x * value, target.y + d.y * value);
+            return Ok(());
+        }
+
+        match action {
+            'L' | 'R' => {
+                if value % 90 != 0 {
+                    return Err(anyhow!("Turn of {value} degrees is not a multiple of 90"));
+                }
+                let turns = if action == 'R' {
+                    value / 90
+                } else {
+                    -value / 90
+                };
+                match self.waypoint.as_mut() {
+                    Some(wp) => *wp = rotate_waypoint(*wp, turns),
+                    None => self.heading = self.heading.rotate(turns),
+                }
+            }
+            'F' => {
+                let step = self.waypoint.unwrap_or_else(|| self.heading.delta());
+                self.pos = Point::new(self.pos.x + step.x * value, self.pos.y + step.y * value);
+            }
+            _ => return Err(anyhow!("Unknown navigation action '{action}'")),
+        }
+        Ok(())
+    }
+
+    /// Apply commands in order.
+    pub fn run(&mut self, commands: impl IntoIterator<Item = (char, i64)>) -> Result<()> {
+        for (action, value) in commands {
+            self.apply(action, value)?;
+        }
+        Ok(())
+    }
+
+    /// Manhattan distance of the ship from the origin.
+    pub fn distance(&self) -> i64 {
+        self.pos.manhattan(Point::new(0, 0))
+    }
 }
 
 //##################################################################################################
 // Session & Networking
 //##################################################################################################
 
-/// Attempt to load session id from env var or SessionID.txt (day folder first, then repo root).
+/// Repo-root config files checked by `load_config`, first match wins.
+const CONFIG_FILES: &[&str] = &["aoc.config", ".aoc.toml"];
+
+/// Settings from `aoc.config` (or `.aoc.toml`): one `key = value` per line with `session`,
+/// `user_agent` and `year`; `#` comments (whole-line or after whitespace) and quoted values are
+/// fine, so simple TOML parses too.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Config {
+    pub session: Option<String>,
+    pub user_agent: Option<String>,
+    pub year: Option<i32>,
+}
+
+/// Read the repo-root config file; missing files or unknown keys just leave fields unset.
+pub fn load_config() -> Config {
+    CONFIG_FILES
+        .iter()
+        .find_map(|path| fs::read_to_string(path).ok())
+        .map(|text| parse_config(&text))
+        .unwrap_or_default()
+}
+
+/// Parse config text (see `Config`). `#` starts a comment only at the beginning of a line or
+/// after whitespace in an unquoted value, so tokens and user agents may contain `#`; quoted
+/// values are taken verbatim up to the closing quote.
+pub fn parse_config(text: &str) -> Config {
+    let mut config = Config::default();
+    for line in lines(text) {
+        let line = line.trim();
+        if line.starts_with('#') {
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        let value = value.trim();
+        let value = match value.chars().next() {
+            Some(q @ ('"' | '\'')) => {
+                let rest = &value[1..];
+                &rest[..rest.find(q).unwrap_or(rest.len())]
+            }
+            _ => value
+                .char_indices()
+                .find(|&(i, c)| c == '#' && (i == 0 || value[..i].ends_with(char::is_whitespace)))
+                .map_or(value, |(i, _)| &value[..i])
+                .trim(),
+        };
+        if value.is_empty() {
+            continue;
+        }
+        match key.trim() {
+            "session" => config.session = Some(value.to_string()),
+            "user_agent" => config.user_agent = Some(value.to_string()),
+            "year" => config.year = value.parse().ok(),
+            _ => {}
+        }
+    }
+    config
+}
+
+/// Puzzle year: `AOC_YEAR`, then the config file, then `DEFAULT_YEAR`.
+pub fn default_year() -> i32 {
+    std::env::var("AOC_YEAR")
+        .ok()
+        .and_then(|y| y.trim().parse().ok())
+        .or_else(|| load_config().year)
+        .unwrap_or(DEFAULT_YEAR)
+}
+
+/// Load the session id: env `AOC_SESSION_ID`, then the config file, then SessionID.txt (day
+/// folder first, then repo root).
 pub fn load_session(day: Option<u8>) -> Result<String> {
     if let Ok(env) = std::env::var("AOC_SESSION_ID") {
         let trimmed = env.trim();
@@ -353,6 +3835,9 @@ pub fn load_session(day: Option<u8>) -> Result<String> {
             return Ok(trimmed.to_string());
         }
     }
+    if let Some(session) = load_config().session {
+        return Ok(session);
+    }
 
     let mut candidates = Vec::new();
     if let Some(d) = day {
@@ -370,15 +3855,16 @@ pub fn load_session(day: Option<u8>) -> Result<String> {
     }
 
     Err(anyhow!(
-        "Missing session cookie. Set AOC_SESSION_ID or place SessionID.txt in the day folder or repo root."
+        "Missing session cookie. Set AOC_SESSION_ID, add session=... to aoc.config, or place SessionID.txt in the day folder or repo root."
     ))
 }
 
-/// Load user agent string (env `AOC_USER_AGENT` or fallback).
+/// Load user agent string (env `AOC_USER_AGENT`, then the config file, else a fallback).
 pub fn load_user_agent() -> String {
     std::env::var("AOC_USER_AGENT")
         .ok()
         .filter(|s| !s.trim().is_empty())
+        .or_else(|| load_config().user_agent)
         .unwrap_or_else(|| USER_AGENT_FALLBACK.to_string())
 }
 
@@ -419,13 +3905,17 @@ impl std::fmt::Display for SubmissionVerdict {
     }
 }
 
-/// Submit an answer to AoC and classify the response.
+/// Submit an answer to AoC and classify the response; the full response page is saved to
+/// `Day_XX/last_submission_response.html` for inspection when the verdict is `Unknown`.
+/// Refuses to submit within `SUBMIT_QUOTA_SECS` of the previous submission (see
+/// `check_submit_quota`).
 pub fn submit_answer(
     day: u8,
     level: u8,
     answer: impl ToString,
     year: i32,
 ) -> Result<SubmissionVerdict> {
+    check_submit_quota()?;
     let session = load_session(Some(day))?;
     let user_agent = load_user_agent();
     let client = http_client(&user_agent)?;
@@ -437,18 +3927,193 @@ pub fn submit_answer(
         .form(&[("level", level.to_string()), ("answer", answer.to_string())])
         .send()
         .context("Failed to submit answer")?;
-
-    if !resp.status().is_success() {
-        return Err(anyhow!("HTTP {} when submitting answer", resp.status()));
+    if let Err(e) = record_submit() {
+        eprintln!("Warning: could not record submission time: {e:#}");
     }
 
+    let status = resp.status();
     let text = resp.text().context("Reading submission response")?;
+    // Keep the whole page around; the verdict only carries a short snippet.
+    let saved = save_submission_response(day, &text);
+    if let Err(e) = &saved {
+        eprintln!("Warning: could not save submission response: {e:#}");
+    }
+
+    if !status.is_success() {
+        return Err(match saved {
+            Ok(path) => anyhow!(
+                "HTTP {status} when submitting answer (body in {})",
+                path.display()
+            ),
+            Err(_) => anyhow!("HTTP {status} when submitting answer"),
+        });
+    }
+
     let verdict = classify_submission(&text);
     Ok(verdict)
 }
 
-fn classify_submission(text: &str) -> SubmissionVerdict {
-    if text.contains("That's the right answer!") {
+/// Minimum gap between submissions enforced by `submit_answer`.
+pub const SUBMIT_QUOTA_SECS: u64 = 60;
+
+/// Repo-root file holding the Unix time of the last submission, so the quota survives restarts.
+const LAST_SUBMIT_FILE: &str = ".aoc_last_submit";
+
+fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// Error if the previous submission (any day) was less than `SUBMIT_QUOTA_SECS` ago, naming the
+/// wait left. `AOC_FORCE_SUBMIT=1` bypasses the check.
+pub fn check_submit_quota() -> Result<()> {
+    if env_flag("AOC_FORCE_SUBMIT") {
+        return Ok(());
+    }
+    let Some(last) = fs::read_to_string(LAST_SUBMIT_FILE)
+        .ok()
+        .and_then(|s| s.trim().parse::<u64>().ok())
+    else {
+        return Ok(());
+    };
+    let elapsed = unix_now().saturating_sub(last);
+    if elapsed < SUBMIT_QUOTA_SECS {
+        return Err(anyhow!(
+            "Last submission was {elapsed}s ago; wait {}s more (or set AOC_FORCE_SUBMIT=1)",
+            SUBMIT_QUOTA_SECS - elapsed
+        ));
+    }
+    Ok(())
+}
+
+fn record_submit() -> Result<()> {
+    fs::write(LAST_SUBMIT_FILE, format!("{}\n", unix_now()))
+        .with_context(|| format!("Failed to write {LAST_SUBMIT_FILE}"))
+}
+
+/// Outcome of `submit_answer_checked`: the verdict plus what the puzzle page says afterwards.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CheckedSubmission {
+    pub verdict: SubmissionVerdict,
+    /// Stars shown on the day page after a `Correct` verdict (`None` for other verdicts, or when
+    /// the page could not be read).
+    pub stars: Option<u8>,
+    /// Whether the page gained a star across the submission (or, if the pre-submit read failed,
+    /// at least shows the star for `level`).
+    pub confirmed: bool,
+}
+
+/// `submit_answer` with a before/after read of the puzzle page: on `Correct` the page is
+/// refetched and the submission only counts as confirmed if the star count went up (guards
+/// against a mis-classified response or a submission for an already-solved part). The status
+/// reads are best effort: a failed fetch never blocks or discards the submission itself.
+pub fn submit_answer_checked(
+    day: u8,
+    level: u8,
+    answer: impl ToString,
+    year: i32,
+) -> Result<CheckedSubmission> {
+    let before = day_stars(day, year).ok();
+    let verdict = submit_answer(day, level, answer, year)?;
+    if verdict != SubmissionVerdict::Correct {
+        return Ok(CheckedSubmission {
+            verdict,
+            stars: None,
+            confirmed: false,
+        });
+    }
+    let after = day_stars(day, year).ok();
+    let confirmed = match (before, after) {
+        (Some(before), Some(after)) => after > before,
+        (None, Some(after)) => after >= level,
+        (_, None) => false,
+    };
+    Ok(CheckedSubmission {
+        verdict,
+        stars: after,
+        confirmed,
+    })
+}
+
+/// Write the raw submission response to `Day_XX/last_submission_response.html`.
+fn save_submission_response(day: u8, body: &str) -> Result<PathBuf> {
+    let path = PathBuf::from(format!("Day_{day:02}/last_submission_response.html"));
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, body).with_context(|| format!("Failed to write {}", path.display()))?;
+    Ok(path)
+}
+
+fn last_answer_path(day: u8) -> PathBuf {
+    PathBuf::from(format!("Day_{day:02}/last_answer.json"))
+}
+
+/// Record the answers of the latest real-input run in `Day_XX/last_answer.json`
+/// (`{"part1": 123, "part2": 456}`) so `submit_cached` can submit them later.
+pub fn save_last_answers(day: u8, answers: [i64; 2]) -> Result<()> {
+    let path = last_answer_path(day);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let json = format!("{{\"part1\": {}, \"part2\": {}}}\n", answers[0], answers[1]);
+    fs::write(&path, json).with_context(|| format!("Failed to write {}", path.display()))
+}
+
+/// Answers saved by `save_last_answers`, as `[part1, part2]`.
+pub fn load_last_answers(day: u8) -> Result<[Option<i64>; 2]> {
+    let path = last_answer_path(day);
+    let text = fs::read_to_string(&path)
+        .with_context(|| format!("No cached answers: {} (run the day first)", path.display()))?;
+    let field =
+        regex::Regex::new(r#""part([12])"\s*:\s*(-?\d+)"#).expect("answer pattern is valid");
+    let mut answers = [None, None];
+    for c in field.captures_iter(&text) {
+        let part: usize = c[1].parse().expect("pattern only matches 1 or 2");
+        answers[part - 1] = c[2].parse().ok();
+    }
+    Ok(answers)
+}
+
+/// Submit the answer for `level` saved by the last run, without recomputing it.
+pub fn submit_cached(day: u8, level: u8, year: i32) -> Result<SubmissionVerdict> {
+    let answers = load_last_answers(day)?;
+    let answer = match level {
+        1 | 2 => answers[level as usize - 1],
+        _ => return Err(anyhow!("Level must be 1 or 2")),
+    }
+    .ok_or_else(|| anyhow!("No cached part {level} answer for day {day}"))?;
+    submit_answer(day, level, answer, year)
+}
+
+/// Submit whichever of `answers` (`[part1, part2]`) matches the part `detect_part` reports. That
+/// relies on `Day_XX/instructions-two.md` being present once part 1 is solved (the scaffolder
+/// writes it on the next fetch); without it part 1 is assumed.
+pub fn submit_current<T: ToString>(
+    day: u8,
+    answers: [T; 2],
+    year: i32,
+) -> Result<SubmissionVerdict> {
+    let level = detect_part(day);
+    let [part1, part2] = answers;
+    let answer = if level == 2 { part2 } else { part1 };
+    submit_answer(day, level, answer, year)
+}
+
+/// Phrases AoC uses for an accepted answer; the final star of a day may only show the
+/// day-complete / every-puzzle banners.
+const CORRECT_PHRASES: &[&str] = &[
+    "That's the right answer!",
+    "one gold star closer",
+    "You have completed Day",
+    "You've finished every puzzle",
+];
+
+/// Classify the HTML/text of an answer response.
+pub fn classify_submission(text: &str) -> SubmissionVerdict {
+    if CORRECT_PHRASES.iter().any(|p| text.contains(p)) {
         SubmissionVerdict::Correct
     } else if text.contains("You gave an answer too recently") {
         SubmissionVerdict::TooSoon
@@ -482,7 +4147,8 @@ pub fn detect_part(day: u8) -> u8 {
     }
 }
 
-/// Load example input if present.
+/// Load example input if present; with `AOC_FETCH_EXAMPLE=1` a missing file is scraped via
+/// `fetch_example`.
 pub fn load_example(day: u8) -> Result<String> {
     let candidates = vec![
         PathBuf::from(format!("Day_{day:02}/Example_{day:02}.txt")),
@@ -493,20 +4159,441 @@ pub fn load_example(day: u8) -> Result<String> {
             return Ok(contents);
         }
     }
+    if env_flag("AOC_FETCH_EXAMPLE") {
+        return fetch_example(day, default_year(), detect_part(day));
+    }
     Err(anyhow!("No example input found for day {day}"))
 }
 
+/// Download the puzzle page, take the first `<pre><code>` block of part `part`'s description (or
+/// of part one's, when part two has none) and cache it to `Day_XX/Example_XX.txt`. Heuristic:
+/// days with several examples (or a small snippet before the real sample) may need the file
+/// fixed by hand.
+pub fn fetch_example(day: u8, year: i32, part: u8) -> Result<String> {
+    let html = fetch_day_page(day, year)?;
+    let example = extract_example(&html, part)
+        .ok_or_else(|| anyhow!("No <pre><code> block found for day {day} part {part}"))?;
+
+    let path = PathBuf::from(format!("Day_{day:02}/Example_{day:02}.txt"));
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&path, &example)
+        .with_context(|| format!("Failed to write example cache: {}", path.display()))?;
+    Ok(example)
+}
+
+/// Raw HTML of the puzzle page, sent with the session cookie when one is available (part 1 is
+/// public; part 2 and the solved-answer lines only appear when logged in).
+fn fetch_day_page(day: u8, year: i32) -> Result<String> {
+    let user_agent = load_user_agent();
+    let client = http_client(&user_agent)?;
+    let url = format!("https://adventofcode.com/{year}/day/{day}");
+    let mut req = client.get(url);
+    if let Ok(session) = load_session(Some(day)) {
+        req = req.header("Cookie", format!("session={session}"));
+    }
+    let resp = req.send().context("Failed to fetch puzzle page")?;
+    if !resp.status().is_success() {
+        return Err(anyhow!("HTTP {} when fetching puzzle page", resp.status()));
+    }
+    resp.text().context("Reading puzzle page")
+}
+
+/// Stars earned on a day (0-2), read from the logged-in puzzle page: each solved part shows a
+/// "Your puzzle answer was" line.
+pub fn day_stars(day: u8, year: i32) -> Result<u8> {
+    let html = fetch_day_page(day, year)?;
+    Ok(count_solved_parts(&html))
+}
+
+fn count_solved_parts(html: &str) -> u8 {
+    html.matches("Your puzzle answer was").count().min(2) as u8
+}
+
+fn extract_example(html: &str, part: u8) -> Option<String> {
+    // Runs once per page fetch; constant patterns, so a compile failure is a bug, not a miss.
+    let article =
+        regex::Regex::new(r"(?s)<article[^>]*>(.*?)</article>").expect("article pattern is valid");
+    let code =
+        regex::Regex::new(r"(?s)<pre><code>(.*?)</code></pre>").expect("code pattern is valid");
+    let tag = regex::Regex::new(r"<[^>]+>").expect("tag pattern is valid");
+
+    let bodies: Vec<&str> = article
+        .captures_iter(html)
+        .filter_map(|c| Some(c.get(1)?.as_str()))
+        .collect();
+    // Part two usually reuses the part-one sample, so fall back to the first article's block.
+    let block = [usize::from(part.max(1)) - 1, 0]
+        .iter()
+        .filter_map(|&i| bodies.get(i))
+        .find_map(|&body| Some(code.captures(body)?.get(1)?.as_str()))?;
+    Some(
+        tag.replace_all(block, "")
+            .replace("&lt;", "<")
+            .replace("&gt;", ">")
+            .replace("&quot;", "\"")
+            .replace("&#39;", "'")
+            .replace("&amp;", "&"),
+    )
+}
+
+/// Load expected example answers from `Expected_XX.txt` (line 1 = part 1, line 2 = part 2).
+/// Blank or missing lines yield `None` so a day can pin part 1 before part 2 is unlocked.
+pub fn load_expected(day: u8) -> Result<[Option<i64>; 2]> {
+    let path = PathBuf::from(format!("Day_{day:02}/Expected_{day:02}.txt"));
+    let contents = fs::read_to_string(&path)
+        .with_context(|| format!("No expected answers found: {}", path.display()))?;
+
+    let mut expected = [None, None];
+    for (slot, line) in expected.iter_mut().zip(lines(&contents)) {
+        let line = line.trim();
+        if !line.is_empty() {
+            *slot = Some(line.parse().with_context(|| {
+                format!("Invalid expected answer '{line}' in {}", path.display())
+            })?);
+        }
+    }
+    Ok(expected)
+}
+
 //##################################################################################################
 // UX Helpers
 //##################################################################################################
 
 /// Simple prompt helper used before submissions.
+/// `AOC_AUTO_CONFIRM=1` skips the prompt entirely (CI / headless runs); `AOC_CONFIRM_TIMEOUT=<secs>`
+/// counts down and proceeds automatically unless Ctrl+C is pressed first.
 pub fn confirm_prompt() -> Result<()> {
-    print!("Press Enter to submit or Ctrl+C to abort... ");
-    io::stdout().flush().ok();
-    let mut buf = String::new();
-    io::stdin()
-        .read_line(&mut buf)
-        .context("Reading confirmation input")?;
+    if env_flag("AOC_AUTO_CONFIRM") {
+        println!("AOC_AUTO_CONFIRM set; submitting without prompt.");
+        return Ok(());
+    }
+
+    let timeout = std::env::var("AOC_CONFIRM_TIMEOUT")
+        .ok()
+        .and_then(|s| s.trim().parse::<u64>().ok());
+    let Some(secs) = timeout else {
+        print!("Press Enter to submit or Ctrl+C to abort... ");
+        io::stdout().flush().ok();
+        let mut buf = String::new();
+        io::stdin()
+            .read_line(&mut buf)
+            .context("Reading confirmation input")?;
+        return Ok(());
+    };
+
+    // Read stdin on a helper thread so the countdown can run alongside it.
+    let (tx, rx) = std::sync::mpsc::channel();
+    std::thread::spawn(move || {
+        let mut buf = String::new();
+        let _ = io::stdin().read_line(&mut buf);
+        let _ = tx.send(());
+    });
+
+    for remaining in (1..=secs).rev() {
+        print!("\rPress Enter to submit or Ctrl+C to abort (auto-submit in {remaining:>3}s)... ");
+        io::stdout().flush().ok();
+        if rx.recv_timeout(std::time::Duration::from_secs(1)).is_ok() {
+            return Ok(());
+        }
+    }
+    println!();
+    Ok(())
+}
+
+/// `--watch` for the day runners: re-runs the current binary (`cargo run --bin dayXX`, or `aoc`)
+/// with the current arguments (minus `--watch`) whenever the day's input/example, its solution module or `src/lib.rs`
+/// changes, clearing the screen before each run. Polls modification times every 500 ms and
+/// runs until Ctrl+C. Refuses `--submit`, which would resubmit on every save.
+#[cfg(feature = "watch")]
+pub fn watch_day(day: u8) -> Result<()> {
+    let forward: Vec<String> = std::env::args()
+        .skip(1)
+        .filter(|a| a != "--watch")
+        .collect();
+    if forward.iter().any(|a| a == "--submit") {
+        return Err(anyhow!("--watch cannot be combined with --submit"));
+    }
+    let paths = [
+        format!("Day_{day:02}/input_{day:02}.txt"),
+        format!("Day_{day:02}/Example_{day:02}.txt"),
+        format!("Day_{day:02}/day{day:02}.rs"),
+        format!("src/solutions/day{day:02}.rs"),
+        "src/lib.rs".to_string(),
+    ];
+    let features = [
+        ("hashing", cfg!(feature = "hashing")),
+        ("rayon", cfg!(feature = "rayon")),
+        ("render", cfg!(feature = "render")),
+        ("serde", cfg!(feature = "serde")),
+        ("watch", true),
+    ]
+    .iter()
+    .filter(|&&(_, on)| on)
+    .map(|&(name, _)| name)
+    .collect::<Vec<_>>()
+    .join(",");
+    let stamp = || -> Vec<Option<std::time::SystemTime>> {
+        paths
+            .iter()
+            .map(|p| fs::metadata(p).and_then(|m| m.modified()).ok())
+            .collect()
+    };
+
+    let mut last = None;
+    loop {
+        if last.as_ref() != Some(&stamp()) {
+            print!("\x1b[2J\x1b[H");
+            io::stdout().flush().ok();
+            let status = std::process::Command::new("cargo")
+                // Same feature set as this process, so cargo doesn't rebuild the lib each time.
+                .args(["run", "--quiet", "--features", &features])
+                .args(["--bin", &runner_bin(day), "--"])
+                .args(&forward)
+                .status()
+                .context("Running cargo")?;
+            if !status.success() {
+                println!("[watch] run failed ({status})");
+            }
+            println!("[watch] waiting for changes (Ctrl+C to stop)...");
+            // Stamp after the run so a first-run input download doesn't trigger a rerun.
+            last = Some(stamp());
+        }
+        std::thread::sleep(std::time::Duration::from_millis(500));
+    }
+}
+
+/// Without the `watch` feature `--watch` only explains how to enable it.
+#[cfg(not(feature = "watch"))]
+pub fn watch_day(day: u8) -> Result<()> {
+    Err(anyhow!(
+        "--watch needs the watch feature: cargo run --features watch --bin {} -- <args> --watch",
+        runner_bin(day)
+    ))
+}
+
+/// Cargo bin name of the running executable, falling back to the per-day `dayXX` runner.
+fn runner_bin(day: u8) -> String {
+    std::env::current_exe()
+        .ok()
+        .and_then(|exe| Some(exe.file_stem()?.to_str()?.to_string()))
+        .unwrap_or_else(|| format!("day{day:02}"))
+}
+
+/// AoC unlocks each day at midnight US Eastern Standard Time, i.e. UTC-5 (December has no DST).
+pub const AOC_UNLOCK_UTC_OFFSET_HOURS: i64 = -5;
+
+/// Moment puzzle `day` of `year` unlocks (December `day`, 00:00 at `AOC_UNLOCK_UTC_OFFSET_HOURS`).
+pub fn unlock_time(day: u8, year: i32) -> std::time::SystemTime {
+    // Days since 1970-01-01 for the civil date (Howard Hinnant's days_from_civil, month = 12).
+    let (y, m, d) = (year as i64, 12i64, day as i64);
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * (m - 3) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    let days = era * 146_097 + doe - 719_468;
+
+    let secs = days * 86_400 - AOC_UNLOCK_UTC_OFFSET_HOURS * 3_600;
+    std::time::UNIX_EPOCH + std::time::Duration::from_secs(secs.max(0) as u64)
+}
+
+/// Sleep until the puzzle unlocks, showing a once-per-second countdown; returns at once if it
+/// already has. Start the runner just before midnight and it fetches as soon as it can.
+pub fn wait_for_unlock(day: u8, year: i32) -> Result<()> {
+    let target = unlock_time(day, year);
+    let mut waited = false;
+    while let Ok(remaining) = target.duration_since(std::time::SystemTime::now()) {
+        if remaining.is_zero() {
+            break;
+        }
+        let secs = remaining.as_secs();
+        print!(
+            "\rDay {day} unlocks in {:02}:{:02}:{:02}... ",
+            secs / 3_600,
+            secs / 60 % 60,
+            secs % 60
+        );
+        io::stdout().flush().ok();
+        std::thread::sleep(remaining.min(std::time::Duration::from_secs(1)));
+        waited = true;
+    }
+    if waited {
+        println!();
+    }
+    Ok(())
+}
+
+/// Whether an env var is set to a truthy value (`1`, `true`, `yes`, `on`).
+fn env_flag(name: &str) -> bool {
+    std::env::var(name)
+        .map(|v| {
+            matches!(
+                v.trim().to_ascii_lowercase().as_str(),
+                "1" | "true" | "yes" | "on"
+            )
+        })
+        .unwrap_or(false)
+}
+
+//##################################################################################################
+// Day Runner CLI
+//##################################################################################################
+
+#[derive(Debug, Default)]
+struct CliArgs {
+    day: Option<u8>,
+    part: Option<u8>,
+    year: i32,
+    example: bool,
+    submit: bool,
+    no_confirm: bool,
+    wait: bool,
+    watch: bool,
+    profile: Option<PathBuf>,
+}
+
+/// Parse runner flags from `std::env::args`; `--day` is only accepted when `day` isn't fixed.
+fn parse_cli_args(day: Option<u8>) -> Result<CliArgs> {
+    let mut args = CliArgs {
+        day,
+        year: default_year(),
+        ..Default::default()
+    };
+
+    let mut iter = std::env::args().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--day" if day.is_none() => {
+                let val = iter
+                    .next()
+                    .ok_or_else(|| anyhow!("--day requires a value"))?;
+                args.day = Some(val.parse()?);
+            }
+            "--part" => {
+                let val = iter
+                    .next()
+                    .ok_or_else(|| anyhow!("--part requires a value"))?;
+                args.part = Some(val.parse()?);
+            }
+            "--year" => {
+                let val = iter
+                    .next()
+                    .ok_or_else(|| anyhow!("--year requires a value"))?;
+                args.year = val.parse()?;
+            }
+            "--profile" => {
+                let val = iter
+                    .next()
+                    .ok_or_else(|| anyhow!("--profile requires a value"))?;
+                args.profile = Some(PathBuf::from(val));
+            }
+            "--example" => args.example = true,
+            "--submit" => args.submit = true,
+            "--no-confirm" => args.no_confirm = true,
+            "--wait" => args.wait = true,
+            "--watch" => args.watch = true,
+            "--help" | "-h" => {
+                print_cli_usage(day);
+                std::process::exit(0);
+            }
+            other => return Err(anyhow!("Unknown argument: {other}")),
+        }
+    }
+
+    Ok(args)
+}
+
+fn print_cli_usage(day: Option<u8>) {
+    let (title, day_flag, example) = match day {
+        Some(day) => (
+            format!("Day {day} runner"),
+            String::new(),
+            format!("Example_{day:02}.txt"),
+        ),
+        None => (
+            "Single runner for every registered day".to_string(),
+            format!(
+                "  --day <N>        Day to run (registered: {:?})\n",
+                solutions::registered_days()
+            ),
+            "Example_XX.txt".to_string(),
+        ),
+    };
+    eprintln!(
+        "\
+{title}
+{day_flag}  --part <1|2>     Force part (default: detect instructions-two.md)
+  --year <YYYY>    Override year (default: {default_year})
+  --example        Use {example} if present
+  --submit         Submit the computed answer
+  --no-confirm     Skip prompt when submitting
+  --wait           Sleep until the puzzle unlocks, then fetch
+  --watch          Re-run on input/source changes (needs --features watch)
+  --profile <CSV>  Append day,part,ms,timestamp rows to CSV
+",
+        default_year = default_year()
+    );
+}
+
+/// Entry point shared by every runner: `aoc` calls it with `None` and takes `--day N`, the
+/// per-day `dayXX` binaries pass their day. Solves both parts through the solutions registry,
+/// then handles `--profile`, answer caching and `--submit`.
+pub fn run_cli(day: Option<u8>) -> Result<()> {
+    let args = parse_cli_args(day)?;
+    let day = args
+        .day
+        .ok_or_else(|| anyhow!("--day is required (see --help)"))?;
+    let (part1, part2) = solutions::solvers_for(day).ok_or_else(|| {
+        anyhow!(
+            "Day {day} has no registered solution (registered: {:?})",
+            solutions::registered_days()
+        )
+    })?;
+    if args.watch {
+        return watch_day(day);
+    }
+    let part = args.part.unwrap_or_else(|| detect_part(day));
+
+    if args.wait && !args.example {
+        wait_for_unlock(day, args.year)?;
+    }
+
+    let raw = if args.example {
+        load_example(day)?
+    } else {
+        get_input(day, args.year)?
+    };
+
+    let (ans1, t1) = time_result_duration(|| part1(&raw))?;
+    println!("Part 1: {ans1} ({})", fmt_duration(t1));
+
+    let (ans2, t2) = time_result_duration(|| part2(&raw))?;
+    println!("Part 2: {ans2} ({})", fmt_duration(t2));
+
+    if let Some(path) = &args.profile {
+        append_profile(path, day, 1, t1.as_millis())?;
+        append_profile(path, day, 2, t2.as_millis())?;
+    }
+
+    if !args.example {
+        save_last_answers(day, [ans1, ans2])?;
+    }
+
+    if args.submit {
+        let answer = match part {
+            1 => ans1,
+            2 => ans2,
+            _ => return Err(anyhow!("Part must be 1 or 2")),
+        };
+
+        if !args.no_confirm {
+            confirm_prompt()?;
+        }
+
+        let verdict = submit_answer(day, part, answer, args.year)?;
+        println!("Submission verdict: {verdict}");
+    }
+
     Ok(())
 }
@@ -0,0 +1,78 @@
+use anyhow::{bail, Result};
+
+use crate::lines;
+
+//##################################################################################################
+// Parsing & Data Prep & Puzzle Logic
+//##################################################################################################
+
+fn parse(input: &str) -> Result<Vec<(char, i64)>> {
+    let mut res = Vec::new();
+    for line in lines(input).filter(|l| !l.is_empty()) {
+        let (dch, num) = line.split_at(1);
+        let dir = match dch.chars().next() {
+            Some(c @ ('L' | 'R')) => c,
+            _ => bail!("Invalid direction in line: {line}"),
+        };
+        let dist: i64 = num.parse()?;
+        res.push((dir, dist));
+    }
+    Ok(res)
+}
+
+/// Count clicks that land on 0 while rotating; the starting position itself is not counted.
+fn zero_hits(pos: i64, dir: char, steps: i64) -> i64 {
+    let m = 100i64;
+    let pos = pos.rem_euclid(m);
+
+    if steps == 0 {
+        return 0;
+    }
+
+    let first = match dir {
+        'R' => (m - pos) % m,
+        'L' => pos % m,
+        _ => unreachable!(),
+    };
+
+    let first = if first == 0 { m } else { first };
+
+    if steps >= first {
+        1 + (steps - first) / m
+    } else {
+        0
+    }
+}
+
+//##################################################################################################
+// Solutions
+//##################################################################################################
+
+pub fn part1(input: &str) -> Result<i64> {
+    let mut pos: i64 = 50;
+    let mut zeros = 0;
+
+    for (dir, dist) in parse(input)? {
+        let delta = if dir == 'R' { dist } else { -dist };
+        pos = (pos + delta).rem_euclid(100);
+        if pos == 0 {
+            zeros += 1;
+        }
+    }
+
+    Ok(zeros)
+}
+
+pub fn part2(input: &str) -> Result<i64> {
+    let mut pos: i64 = 50;
+    let mut zeros = 0;
+
+    for (dir, dist) in parse(input)? {
+        zeros += zero_hits(pos, dir, dist);
+
+        let delta = if dir == 'R' { dist } else { -dist };
+        pos = (pos + delta).rem_euclid(100);
+    }
+
+    Ok(zeros)
+}
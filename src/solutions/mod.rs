@@ -0,0 +1,55 @@
+use anyhow::{bail, Context, Result};
+
+pub mod day01;
+pub mod day02;
+
+/// Signature shared by every `partN` solver.
+pub type Solver = fn(&str) -> Result<i64>;
+
+/// Registered solutions as `(day, part1, part2)`; the scaffolder appends new days here.
+pub const SOLUTIONS: &[(u8, Solver, Solver)] = &[
+    (1, day01::part1, day01::part2),
+    (2, day02::part1, day02::part2),
+];
+
+/// Registered days in ascending order.
+pub fn registered_days() -> Vec<u8> {
+    let mut days: Vec<u8> = SOLUTIONS.iter().map(|&(day, _, _)| day).collect();
+    days.sort_unstable();
+    days
+}
+
+/// Solvers `(part1, part2)` for `day`, if registered.
+pub fn solvers_for(day: u8) -> Option<(Solver, Solver)> {
+    SOLUTIONS
+        .iter()
+        .find(|&&(d, _, _)| d == day)
+        .map(|&(_, p1, p2)| (p1, p2))
+}
+
+/// Parse a comma-separated day list (`1,3,5`), rejecting days without a registered solution.
+pub fn parse_day_list(list: &str) -> Result<Vec<u8>> {
+    let mut days = Vec::new();
+    for tok in list.split(',').map(str::trim).filter(|t| !t.is_empty()) {
+        let day: u8 = tok
+            .parse()
+            .with_context(|| format!("Invalid day '{tok}'"))?;
+        if solvers_for(day).is_none() {
+            bail!("Day {day} has no registered solution");
+        }
+        if !days.contains(&day) {
+            days.push(day);
+        }
+    }
+    days.sort_unstable();
+    Ok(days)
+}
+
+/// Registered days filtered by an explicit `only` list and/or an inclusive `since` lower bound.
+pub fn select_days(only: Option<&[u8]>, since: Option<u8>) -> Vec<u8> {
+    registered_days()
+        .into_iter()
+        .filter(|day| only.map_or(true, |days| days.contains(day)))
+        .filter(|&day| since.map_or(true, |s| day >= s))
+        .collect()
+}
@@ -0,0 +1,191 @@
+use std::collections::{HashMap, VecDeque};
+
+use anyhow::{anyhow, bail, Context, Result};
+
+pub use crate::vm::Status;
+
+//##################################################################################################
+// Tape Machine
+//##################################################################################################
+
+/// Opcode handler: reads/writes parameters via the machine, advances `ip` itself and reports
+/// whether to keep going (`Running`), pause for input (`NeedInput`, leave `ip` untouched) or stop.
+pub type OpHandler = fn(&mut TapeMachine) -> Result<Status>;
+
+/// Self-modifying tape machine (intcode-style): memory is a `Vec<i64>` that grows on demand,
+/// instructions encode parameter modes above the two-digit opcode (`0` position, `1` immediate,
+/// `2` relative) and opcodes are dispatched through a table so dialects can add or replace ops.
+pub struct TapeMachine {
+    pub memory: Vec<i64>,
+    pub ip: usize,
+    pub relative_base: i64,
+    pub input: VecDeque<i64>,
+    pub output: VecDeque<i64>,
+    pub halted: bool,
+    ops: HashMap<i64, OpHandler>,
+}
+
+/// Parse a comma-separated program into memory.
+pub fn parse_tape(input: &str) -> Result<Vec<i64>> {
+    input
+        .trim()
+        .split(',')
+        .map(|tok| {
+            tok.trim()
+                .parse::<i64>()
+                .with_context(|| format!("Invalid tape value '{tok}'"))
+        })
+        .collect()
+}
+
+impl TapeMachine {
+    /// Machine with an empty opcode table; register handlers with `with_op`.
+    pub fn new(memory: Vec<i64>) -> Self {
+        Self {
+            memory,
+            ip: 0,
+            relative_base: 0,
+            input: VecDeque::new(),
+            output: VecDeque::new(),
+            halted: false,
+            ops: HashMap::new(),
+        }
+    }
+
+    /// Machine preloaded with the full intcode opcode set (1-9 and 99).
+    pub fn intcode(memory: Vec<i64>) -> Self {
+        Self::new(memory)
+            .with_op(1, |m| m.binary_op(i64::checked_add))
+            .with_op(2, |m| m.binary_op(i64::checked_mul))
+            .with_op(3, |m| match m.input.pop_front() {
+                Some(v) => {
+                    m.write_param(1, v)?;
+                    m.ip += 2;
+                    Ok(Status::Running)
+                }
+                None => Ok(Status::NeedInput),
+            })
+            .with_op(4, |m| {
+                let v = m.param(1)?;
+                m.output.push_back(v);
+                m.ip += 2;
+                Ok(Status::Running)
+            })
+            .with_op(5, |m| m.jump_if(|v| v != 0))
+            .with_op(6, |m| m.jump_if(|v| v == 0))
+            .with_op(7, |m| m.binary_op(|a, b| Some((a < b) as i64)))
+            .with_op(8, |m| m.binary_op(|a, b| Some((a == b) as i64)))
+            .with_op(9, |m| {
+                let offset = m.param(1)?;
+                m.relative_base = m
+                    .relative_base
+                    .checked_add(offset)
+                    .ok_or_else(|| anyhow!("Relative base overflow at ip {}", m.ip))?;
+                m.ip += 2;
+                Ok(Status::Running)
+            })
+            .with_op(99, |_| Ok(Status::Halted))
+    }
+
+    /// Register (or replace) the handler for `code`.
+    pub fn with_op(mut self, code: i64, handler: OpHandler) -> Self {
+        self.ops.insert(code, handler);
+        self
+    }
+
+    /// Read memory, treating unwritten cells as 0.
+    pub fn read(&self, addr: usize) -> i64 {
+        self.memory.get(addr).copied().unwrap_or(0)
+    }
+
+    /// Write memory, growing it as needed.
+    pub fn write(&mut self, addr: usize, value: i64) {
+        if addr >= self.memory.len() {
+            self.memory.resize(addr + 1, 0);
+        }
+        self.memory[addr] = value;
+    }
+
+    /// Addressing mode of parameter `n` (1-based) for the current instruction.
+    fn mode(&self, n: u32) -> i64 {
+        self.read(self.ip) / 10i64.pow(n + 1) % 10
+    }
+
+    /// Address referenced by parameter `n` in position or relative mode.
+    fn param_addr(&self, n: u32) -> Result<usize> {
+        let raw = self.read(self.ip + n as usize);
+        let addr = match self.mode(n) {
+            0 => raw,
+            2 => self
+                .relative_base
+                .checked_add(raw)
+                .ok_or_else(|| anyhow!("Relative address overflow at ip {}", self.ip))?,
+            m => bail!("Mode {m} is not addressable (ip {})", self.ip),
+        };
+        usize::try_from(addr).map_err(|_| anyhow!("Negative address {addr} at ip {}", self.ip))
+    }
+
+    /// Value of parameter `n` (1-based), honouring its mode.
+    pub fn param(&self, n: u32) -> Result<i64> {
+        match self.mode(n) {
+            1 => Ok(self.read(self.ip + n as usize)),
+            _ => Ok(self.read(self.param_addr(n)?)),
+        }
+    }
+
+    /// Write to the address named by parameter `n` (1-based).
+    pub fn write_param(&mut self, n: u32, value: i64) -> Result<()> {
+        let addr = self.param_addr(n)?;
+        self.write(addr, value);
+        Ok(())
+    }
+
+    /// `p3 = f(p1, p2)` then skip four cells; the shape of add/mul/lt/eq. `f` returns `None` on
+    /// i64 overflow (pass `i64::checked_add` and friends), which is reported as an error.
+    pub fn binary_op(&mut self, f: impl Fn(i64, i64) -> Option<i64>) -> Result<Status> {
+        let v = f(self.param(1)?, self.param(2)?)
+            .ok_or_else(|| anyhow!("i64 overflow at ip {}", self.ip))?;
+        self.write_param(3, v)?;
+        self.ip += 4;
+        Ok(Status::Running)
+    }
+
+    /// Jump to `p2` when `cond(p1)` holds, else skip three cells.
+    pub fn jump_if(&mut self, cond: impl Fn(i64) -> bool) -> Result<Status> {
+        if cond(self.param(1)?) {
+            let target = self.param(2)?;
+            self.ip = usize::try_from(target)
+                .map_err(|_| anyhow!("Negative jump target {target} at ip {}", self.ip))?;
+        } else {
+            self.ip += 3;
+        }
+        Ok(Status::Running)
+    }
+
+    /// Execute one instruction.
+    pub fn step(&mut self) -> Result<Status> {
+        if self.halted {
+            return Ok(Status::Halted);
+        }
+        let code = self.read(self.ip) % 100;
+        let handler = *self
+            .ops
+            .get(&code)
+            .ok_or_else(|| anyhow!("Unknown opcode {code} at ip {}", self.ip))?;
+        let status = handler(self)?;
+        if status == Status::Halted {
+            self.halted = true;
+        }
+        Ok(status)
+    }
+
+    /// Run until the program halts or needs more input; queue input and call again to resume.
+    pub fn run_until_input_or_halt(&mut self) -> Result<Status> {
+        loop {
+            match self.step()? {
+                Status::Running => {}
+                other => return Ok(other),
+            }
+        }
+    }
+}
@@ -0,0 +1,273 @@
+use std::collections::VecDeque;
+
+use anyhow::{anyhow, bail, Context, Result};
+
+use crate::lines;
+
+//##################################################################################################
+// Instructions & Parsing
+//##################################################################################################
+
+/// Instruction operand: a single-letter register or an immediate value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operand {
+    Reg(char),
+    Imm(i64),
+}
+
+/// Opcodes shared by the AoC assembly dialects (ALU, assembunny, duet, ...).
+/// Anything unrecognized parses as `Custom` and is dispatched to the machine's custom handler.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Instruction {
+    /// `inp a` — pop the next input value into `a`.
+    Inp(char),
+    /// `cpy x a` — copy `x` into `a`.
+    Cpy(Operand, char),
+    /// `set a x` — set `a` to `x`.
+    Set(char, Operand),
+    Add(char, Operand),
+    Sub(char, Operand),
+    Mul(char, Operand),
+    /// `div a x` — integer division truncating toward zero.
+    Div(char, Operand),
+    Mod(char, Operand),
+    /// `eql a x` — `a = 1` if `a == x` else `0`.
+    Eql(char, Operand),
+    Inc(char),
+    Dec(char),
+    /// `jnz x y` — jump by `y` if `x != 0`.
+    Jnz(Operand, Operand),
+    /// `jmp x` — jump by `x`.
+    Jmp(Operand),
+    /// `out x` — push `x` to the output queue.
+    Out(Operand),
+    Custom {
+        op: String,
+        args: Vec<Operand>,
+    },
+}
+
+/// Parse one instruction per non-empty line (`op arg arg`); unknown opcodes become `Custom`.
+pub fn parse_program(input: &str) -> Result<Vec<Instruction>> {
+    lines(input)
+        .map(str::trim)
+        .enumerate()
+        .filter(|(_, l)| !l.is_empty())
+        .map(|(i, l)| parse_instruction(l).with_context(|| format!("line {}: {l}", i + 1)))
+        .collect()
+}
+
+fn parse_instruction(line: &str) -> Result<Instruction> {
+    let mut toks = line.split_whitespace();
+    let op = toks
+        .next()
+        .ok_or_else(|| anyhow!("Empty instruction"))?
+        .to_ascii_lowercase();
+    let args = toks.map(parse_operand).collect::<Result<Vec<_>>>()?;
+
+    let reg = |i: usize| -> Result<char> {
+        match args.get(i) {
+            Some(Operand::Reg(r)) => Ok(*r),
+            Some(Operand::Imm(v)) => bail!("Expected register, found {v}"),
+            None => bail!("Missing operand {}", i + 1),
+        }
+    };
+    let val = |i: usize| -> Result<Operand> {
+        args.get(i)
+            .copied()
+            .ok_or_else(|| anyhow!("Missing operand {}", i + 1))
+    };
+
+    let ins = match op.as_str() {
+        "inp" => Instruction::Inp(reg(0)?),
+        "cpy" => Instruction::Cpy(val(0)?, reg(1)?),
+        "set" => Instruction::Set(reg(0)?, val(1)?),
+        "add" => Instruction::Add(reg(0)?, val(1)?),
+        "sub" => Instruction::Sub(reg(0)?, val(1)?),
+        "mul" => Instruction::Mul(reg(0)?, val(1)?),
+        "div" => Instruction::Div(reg(0)?, val(1)?),
+        "mod" => Instruction::Mod(reg(0)?, val(1)?),
+        "eql" => Instruction::Eql(reg(0)?, val(1)?),
+        "inc" => Instruction::Inc(reg(0)?),
+        "dec" => Instruction::Dec(reg(0)?),
+        "jnz" => Instruction::Jnz(val(0)?, val(1)?),
+        "jmp" => Instruction::Jmp(val(0)?),
+        "out" => Instruction::Out(val(0)?),
+        _ => Instruction::Custom { op, args },
+    };
+    Ok(ins)
+}
+
+fn parse_operand(tok: &str) -> Result<Operand> {
+    if let Ok(v) = tok.trim_end_matches(',').parse::<i64>() {
+        return Ok(Operand::Imm(v));
+    }
+    let mut chars = tok.trim_end_matches(',').chars();
+    match (chars.next(), chars.next()) {
+        (Some(c), None) if c.is_ascii_alphabetic() => Ok(Operand::Reg(c.to_ascii_lowercase())),
+        _ => bail!("Invalid operand: {tok}"),
+    }
+}
+
+//##################################################################################################
+// Machine
+//##################################################################################################
+
+/// Why `step`/`run` stopped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Status {
+    Running,
+    /// `inp` executed with an empty input queue; push input and call `run` again.
+    NeedInput,
+    /// Instruction pointer left the program.
+    Halted,
+}
+
+/// Handler for `Instruction::Custom`; returns the instruction-pointer delta (usually `1`).
+pub type CustomOp = dyn FnMut(&mut Machine, &str, &[Operand]) -> Result<i64>;
+
+/// Register machine with 26 single-letter registers (`a`..=`z`) and input/output queues.
+pub struct Machine {
+    pub regs: [i64; 26],
+    pub ip: i64,
+    pub input: VecDeque<i64>,
+    pub output: Vec<i64>,
+    pub program: Vec<Instruction>,
+    custom: Option<Box<CustomOp>>,
+}
+
+impl Machine {
+    /// Create a machine with all registers zeroed.
+    pub fn new(program: Vec<Instruction>) -> Self {
+        Self {
+            regs: [0; 26],
+            ip: 0,
+            input: VecDeque::new(),
+            output: Vec::new(),
+            program,
+            custom: None,
+        }
+    }
+
+    /// Install a handler for opcodes the parser didn't recognize.
+    pub fn with_custom_op(
+        mut self,
+        handler: impl FnMut(&mut Machine, &str, &[Operand]) -> Result<i64> + 'static,
+    ) -> Self {
+        self.custom = Some(Box::new(handler));
+        self
+    }
+
+    /// Read a register.
+    pub fn reg(&self, r: char) -> i64 {
+        self.regs[reg_index(r)]
+    }
+
+    /// Write a register.
+    pub fn set_reg(&mut self, r: char, v: i64) {
+        self.regs[reg_index(r)] = v;
+    }
+
+    /// Resolve an operand to its value.
+    pub fn value(&self, op: Operand) -> i64 {
+        match op {
+            Operand::Reg(r) => self.reg(r),
+            Operand::Imm(v) => v,
+        }
+    }
+
+    /// Execute a single instruction.
+    pub fn step(&mut self) -> Result<Status> {
+        let Some(ins) = usize::try_from(self.ip)
+            .ok()
+            .and_then(|ip| self.program.get(ip))
+            .cloned()
+        else {
+            return Ok(Status::Halted);
+        };
+
+        let mut delta = 1;
+        match ins {
+            Instruction::Inp(a) => match self.input.pop_front() {
+                Some(v) => self.set_reg(a, v),
+                None => return Ok(Status::NeedInput),
+            },
+            Instruction::Cpy(x, a) | Instruction::Set(a, x) => self.set_reg(a, self.value(x)),
+            Instruction::Add(a, x) => {
+                let v = self.reg(a).checked_add(self.value(x));
+                self.set_reg(a, self.overflow_check(v, "add")?);
+            }
+            Instruction::Sub(a, x) => {
+                let v = self.reg(a).checked_sub(self.value(x));
+                self.set_reg(a, self.overflow_check(v, "sub")?);
+            }
+            Instruction::Mul(a, x) => {
+                let v = self.reg(a).checked_mul(self.value(x));
+                self.set_reg(a, self.overflow_check(v, "mul")?);
+            }
+            Instruction::Div(a, x) => {
+                let v = self
+                    .reg(a)
+                    .checked_div(self.value(x))
+                    .ok_or_else(|| anyhow!("Division by zero at ip {}", self.ip))?;
+                self.set_reg(a, v);
+            }
+            Instruction::Mod(a, x) => {
+                let v = self
+                    .reg(a)
+                    .checked_rem(self.value(x))
+                    .ok_or_else(|| anyhow!("Modulo by zero at ip {}", self.ip))?;
+                self.set_reg(a, v);
+            }
+            Instruction::Eql(a, x) => self.set_reg(a, (self.reg(a) == self.value(x)) as i64),
+            Instruction::Inc(a) => {
+                let v = self.reg(a).checked_add(1);
+                self.set_reg(a, self.overflow_check(v, "inc")?);
+            }
+            Instruction::Dec(a) => {
+                let v = self.reg(a).checked_sub(1);
+                self.set_reg(a, self.overflow_check(v, "dec")?);
+            }
+            Instruction::Jnz(x, y) => {
+                if self.value(x) != 0 {
+                    delta = self.value(y);
+                }
+            }
+            Instruction::Jmp(x) => delta = self.value(x),
+            Instruction::Out(x) => self.output.push(self.value(x)),
+            Instruction::Custom { op, args } => {
+                let mut handler = self
+                    .custom
+                    .take()
+                    .ok_or_else(|| anyhow!("Unknown opcode '{op}' at ip {}", self.ip))?;
+                let res = handler(self, &op, &args);
+                self.custom = Some(handler);
+                delta = res?;
+            }
+        }
+
+        // A jump past either end of i64 lands outside the program all the same, i.e. halts.
+        self.ip = self.ip.saturating_add(delta);
+        Ok(Status::Running)
+    }
+
+    /// Unwrap a `checked_*` result, reporting i64 overflow as an error instead of panicking.
+    fn overflow_check(&self, value: Option<i64>, op: &str) -> Result<i64> {
+        value.ok_or_else(|| anyhow!("i64 overflow in '{op}' at ip {}", self.ip))
+    }
+
+    /// Run until the program halts or blocks on input.
+    pub fn run(&mut self) -> Result<Status> {
+        loop {
+            match self.step()? {
+                Status::Running => {}
+                other => return Ok(other),
+            }
+        }
+    }
+}
+
+fn reg_index(r: char) -> usize {
+    assert!(r.is_ascii_lowercase(), "Invalid register '{r}'");
+    (r as u8 - b'a') as usize
+}
@@ -0,0 +1,28 @@
+use aoc2025::solutions::SOLUTIONS;
+use aoc2025::{load_example, load_expected};
+
+/// Run every registered day against `Example_XX.txt` and compare with `Expected_XX.txt`.
+/// Days missing either file are skipped so freshly scaffolded days don't fail the suite.
+#[test]
+fn examples_match_expected() {
+    let mut failures = Vec::new();
+
+    for &(day, part1, part2) in SOLUTIONS {
+        let (Ok(example), Ok(expected)) = (load_example(day), load_expected(day)) else {
+            continue;
+        };
+
+        for (part, solver, want) in [(1, part1, expected[0]), (2, part2, expected[1])] {
+            let Some(want) = want else {
+                continue;
+            };
+            match solver(&example) {
+                Ok(got) if got == want => {}
+                Ok(got) => failures.push(format!("day {day} part {part}: got {got}, want {want}")),
+                Err(e) => failures.push(format!("day {day} part {part}: error {e:#}")),
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
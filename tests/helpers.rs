@@ -0,0 +1,894 @@
+use aoc2025::*;
+
+#[test]
+fn radix_round_trips() {
+    for radix in [2, 3, 8, 10, 16, 36] {
+        for n in [
+            0,
+            1,
+            -1,
+            7,
+            255,
+            -4096,
+            1_234_567_890,
+            i64::MAX,
+            i64::MIN + 1,
+        ] {
+            let s = to_radix(n, radix);
+            assert_eq!(from_radix(&s, radix).unwrap(), n, "base {radix}: {s}");
+        }
+    }
+    assert_eq!(to_radix(255, 16), "ff");
+    assert_eq!(to_radix(-5, 2), "-101");
+    assert!(from_radix("12", 2).is_err());
+    assert!(from_radix("1", 1).is_err());
+    assert!(from_radix("1", 37).is_err());
+}
+
+#[test]
+fn snafu_matches_known_values() {
+    let table = [
+        (1, "1"),
+        (3, "1="),
+        (8, "2="),
+        (10, "20"),
+        (2022, "1=11-2"),
+        (12345, "1-0---0"),
+        (314159265, "1121-1110-1=0"),
+        (4890, "2=-1=0"),
+    ];
+    for (n, s) in table {
+        assert_eq!(to_snafu(n), s);
+        assert_eq!(from_snafu(s).unwrap(), n);
+    }
+    for n in -500..500 {
+        assert_eq!(from_snafu(&to_snafu(n)).unwrap(), n);
+    }
+    assert!(from_snafu("13").is_err());
+    assert_eq!(from_snafu(&to_snafu(i64::MAX)).unwrap(), i64::MAX);
+    assert_eq!(from_snafu(&to_snafu(i64::MIN)).unwrap(), i64::MIN);
+    assert!(from_snafu(&"2".repeat(28)).is_err());
+    assert!(from_snafu(&"2".repeat(80)).is_err());
+}
+
+#[test]
+fn int_grid_accepts_extra_separators() {
+    let input = "1,2, 3\n-4 ,5\t,6\n7,,8 9";
+    assert_eq!(
+        parse_int_grid_with(input, &[',']).unwrap(),
+        vec![vec![1, 2, 3], vec![-4, 5, 6], vec![7, 8, 9]]
+    );
+    assert_eq!(
+        parse_int_grid_with("1;2 3\n4; 5;6", &[';']).unwrap(),
+        vec![vec![1, 2, 3], vec![4, 5, 6]]
+    );
+    assert_eq!(
+        parse_int_grid("1  2\n 3 4").unwrap(),
+        vec![vec![1, 2], vec![3, 4]]
+    );
+    assert!(parse_int_grid("1,2,3").is_err());
+}
+
+#[test]
+fn int_grid_accepts_padded_signed_columns() {
+    let table = "   1  -20    +3
+  40    5  -600
+ +700   60     7
+";
+    assert_eq!(
+        parse_int_grid(table).unwrap(),
+        vec![vec![1, -20, 3], vec![40, 5, -600], vec![700, 60, 7]]
+    );
+    assert_eq!(
+        parse_int_grid_with(" +1 ,  -2 ,+3", &[',']).unwrap(),
+        vec![vec![1, -2, 3]]
+    );
+    assert!(parse_int_grid("+-3").is_err());
+    assert!(parse_int_grid("+ 3").is_err());
+}
+
+#[test]
+fn mirror_lines_match_example() {
+    let a = parse_char_grid(
+        "#.##..##.\n..#.##.#.\n##......#\n##......#\n..#.##.#.\n..##..##.\n#.#.##.#.",
+    );
+    let b = parse_char_grid(
+        "#...##..#\n#....#..#\n..##..###\n#####.##.\n#####.##.\n..##..###\n#....#..#",
+    );
+
+    assert_eq!(find_vertical_reflection(&a), Some(5));
+    assert_eq!(find_horizontal_reflection(&a), None);
+    assert_eq!(find_horizontal_reflection(&b), Some(4));
+    assert_eq!(find_vertical_reflection(&b), None);
+
+    assert_eq!(find_horizontal_reflection_with_smudge(&a), Some(3));
+    assert_eq!(find_horizontal_reflection_with_smudge(&b), Some(1));
+    assert_eq!(find_vertical_reflection_with_smudge(&a), None);
+}
+
+#[test]
+fn captcha_sums_next_and_halfway() {
+    for (input, want) in [("1122", 3), ("1111", 4), ("1234", 0), ("91212129", 9)] {
+        assert_eq!(sum_matching_offset(&digits(input), 1), want, "{input}");
+    }
+    for (input, want) in [
+        ("1212", 6),
+        ("1221", 0),
+        ("123425", 4),
+        ("123123", 12),
+        ("12131415", 4),
+    ] {
+        let d = digits(input);
+        assert_eq!(sum_matching_offset(&d, d.len() / 2), want, "{input}");
+    }
+    assert_eq!(sum_matching_offset(&[], 1), 0);
+}
+
+#[test]
+fn navigator_follows_heading_and_waypoint() {
+    let commands = [('F', 10), ('N', 3), ('F', 7), ('R', 90), ('F', 11)];
+
+    let mut ship = Navigator::new();
+    ship.run(commands).unwrap();
+    assert_eq!(ship.pos, Point::new(17, 8));
+    assert_eq!(ship.heading, Dir4::Down);
+    assert_eq!(ship.distance(), 25);
+
+    let mut ship = Navigator::with_waypoint(Point::new(10, -1));
+    ship.run(commands).unwrap();
+    assert_eq!(ship.waypoint, Some(Point::new(4, 10)));
+    assert_eq!(ship.distance(), 286);
+
+    let wp = Point::new(10, -4);
+    assert_eq!(rotate_waypoint(wp, 1), Point::new(4, 10));
+    assert_eq!(rotate_waypoint(wp, -1), rotate_waypoint(wp, 3));
+    assert_eq!(rotate_waypoint(wp, 2), Point::new(-10, 4));
+    assert!(Navigator::new().apply('R', 45).is_err());
+}
+
+#[test]
+fn divisor_helpers_match_known_values() {
+    assert_eq!(divisors(1), vec![1]);
+    assert_eq!(divisors(12), vec![1, 2, 3, 4, 6, 12]);
+    assert_eq!(divisors(49), vec![1, 7, 49]);
+    assert_eq!(divisors(13), vec![1, 13]);
+    assert!(divisors(0).is_empty());
+
+    // Perfect numbers: sigma(n) == 2n.
+    for n in [6, 28, 496, 8128, 33_550_336] {
+        assert_eq!(sigma(n), 2 * n, "sigma({n})");
+    }
+    assert_eq!(sigma(9), 13);
+    assert_eq!(divisor_count(36), 9);
+    assert_eq!(divisor_count(97), 2);
+}
+
+#[test]
+fn crate_stacks_and_moves_parse() {
+    let input = "    [D]    \n[N] [C]    \n[Z] [M] [P]\n 1   2   3 \n\nmove 1 from 2 to 1\nmove 3 from 1 to 3\n";
+    let blocks = split_blocks(input);
+
+    let stacks = parse_crate_stacks(blocks[0]);
+    assert_eq!(stacks, vec![vec!['Z', 'N'], vec!['M', 'C', 'D'], vec!['P']]);
+
+    let moves = parse_moves(blocks[1]).unwrap();
+    assert_eq!(moves, vec![(1, 2, 1), (3, 1, 3)]);
+
+    // Trailing spaces trimmed by an editor must not drop the last column.
+    let stacks = parse_crate_stacks("[A]     [C]\n[B] [D] [E]\n 1   2   3");
+    assert_eq!(stacks, vec![vec!['B', 'A'], vec!['D'], vec!['E', 'C']]);
+
+    assert!(parse_moves("move x from 1 to 2").is_err());
+    assert!(parse_moves("shift 1 from 1 to 2").is_err());
+}
+
+#[test]
+fn submission_messages_classify() {
+    let cases = [
+        (
+            "That's the right answer! You are one gold star closer to decorating the North Pole.",
+            SubmissionVerdict::Correct,
+        ),
+        (
+            "You are one gold star closer to finding the Chief Historian.",
+            SubmissionVerdict::Correct,
+        ),
+        (
+            "Congratulations! You have completed Day 12! [Return to Your Advent Calendar]",
+            SubmissionVerdict::Correct,
+        ),
+        (
+            "Congratulations! You've finished every puzzle in Advent of Code 2025!",
+            SubmissionVerdict::Correct,
+        ),
+        (
+            "That's not the right answer; your answer is too low.",
+            SubmissionVerdict::TooLow,
+        ),
+        (
+            "That's not the right answer; your answer is too high.",
+            SubmissionVerdict::TooHigh,
+        ),
+        ("That's not the right answer.", SubmissionVerdict::Wrong),
+        (
+            "You gave an answer too recently; you have to wait after submitting an answer.",
+            SubmissionVerdict::TooSoon,
+        ),
+        (
+            "You don't seem to be solving the right level.  Did you already complete it?",
+            SubmissionVerdict::AlreadySolved,
+        ),
+    ];
+    for (text, want) in cases {
+        assert_eq!(classify_submission(text), want, "{text}");
+    }
+    assert!(matches!(
+        classify_submission("<html>maintenance</html>"),
+        SubmissionVerdict::Unknown(_)
+    ));
+}
+
+#[test]
+fn spiral_coord_matches_known_distances() {
+    let origin = Point::new(0, 0);
+    for (n, want) in [(1, 0), (12, 3), (23, 2), (1024, 31)] {
+        assert_eq!(spiral_coord(n).manhattan(origin), want, "square {n}");
+    }
+    for (i, pt) in spiral_from(origin).take(500).enumerate() {
+        assert_eq!(spiral_coord(i as u64 + 1), pt, "square {}", i + 1);
+    }
+    // Large odd squares close a ring exactly; the next square opens the following one.
+    let k: i64 = 1_000_000_000;
+    let corner = (2 * k as u64 + 1).pow(2);
+    assert_eq!(spiral_coord(corner), Point::new(k, k));
+    assert_eq!(spiral_coord(corner + 1), Point::new(k + 1, k));
+}
+
+#[test]
+fn durations_format_with_three_significant_figures() {
+    use std::time::Duration;
+    let cases = [
+        (Duration::from_nanos(0), "0ns"),
+        (Duration::from_nanos(850), "850ns"),
+        (Duration::from_micros(342), "342µs"),
+        (Duration::from_micros(1_200), "1.2ms"),
+        (Duration::from_micros(12_345), "12.3ms"),
+        (Duration::from_millis(3_450), "3.45s"),
+        (Duration::from_nanos(999_700), "1ms"),
+        (Duration::from_secs(125), "125s"),
+    ];
+    for (d, want) in cases {
+        assert_eq!(fmt_duration(d), want, "{d:?}");
+    }
+}
+
+#[test]
+fn expansion_matches_galaxy_example() {
+    let image = "\
+...#......
+.......#..
+#.........
+..........
+......#...
+.#........
+.........#
+..........
+.......#..
+#...#.....";
+    let galaxies: Vec<Point> = parse_char_grid(image)
+        .iter()
+        .enumerate()
+        .flat_map(|(y, row)| {
+            row.iter()
+                .enumerate()
+                .filter(|&(_, &c)| c == '#')
+                .map(move |(x, _)| Point::new(x as i64, y as i64))
+        })
+        .collect();
+    let (rows, cols) = empty_rows_cols(&galaxies);
+    assert_eq!(
+        (rows.as_slice(), cols.as_slice()),
+        (&[3, 7][..], &[2, 5, 8][..])
+    );
+
+    let doubled = expand_empty(&galaxies, &rows, &cols, 2);
+    assert_eq!(doubled[0], Point::new(4, 0));
+    assert_eq!(doubled[8], Point::new(5, 11));
+    for (factor, want) in [(2, 374), (10, 1030), (100, 8410)] {
+        let expanded = expand_empty(&galaxies, &rows, &cols, factor);
+        assert_eq!(sum_pairwise_manhattan(&expanded), want, "factor {factor}");
+    }
+}
+
+fn lit_grid(picture: &str) -> Vec<Vec<bool>> {
+    parse_char_grid(picture)
+        .into_iter()
+        .map(|row| row.into_iter().map(|c| c == '#').collect())
+        .collect()
+}
+
+#[test]
+fn ocr_reads_aoc_letters() {
+    let picture = "\
+..#..#.####.###..#....
+..#..#.#....#..#.#....
+..####.###..#..#.#....
+..#..#.#....###..#....
+..#..#.#....#.#..#....
+..#..#.####.#..#.####.";
+    assert_eq!(ocr(&lit_grid(picture)), "HERL");
+
+    // I and J have a blank first column; cells still start on the 5-column boundary.
+    let blank_first_column = "\
+.###.####...##
+..#..#.......#
+..#..###.....#
+..#..#.......#
+..#..#....#..#
+.###.#.....##.";
+    assert_eq!(ocr(&lit_grid(blank_first_column)), "IFJ");
+
+    let unknown = "\
+#...#
+.#.#.
+..#..
+.#.#.
+#...#
+.....";
+    assert_eq!(ocr(&lit_grid(unknown)), "?");
+    assert_eq!(ocr(&[]), "");
+}
+
+#[test]
+fn schematic_numbers_and_gears() {
+    let schematic = "\
+467..114..
+...*......
+..35..633.
+......#...
+617*......
+.....+.58.
+..592.....
+......755.
+...$.*....
+.664.598..";
+    let grid = parse_char_grid(schematic);
+    let numbers = numbers_adjacent_to(&grid, |c| c != '.' && !c.is_ascii_digit());
+    assert_eq!(numbers.len(), 10);
+    assert_eq!(numbers[0], (467, vec![Point::new(3, 1)]));
+    assert_eq!(numbers[1], (114, vec![]));
+
+    let part_sum: u64 = numbers
+        .iter()
+        .filter(|(_, syms)| !syms.is_empty())
+        .map(|(n, _)| n)
+        .sum();
+    assert_eq!(part_sum, 4361);
+
+    let gear_sum: u64 = numbers_by_symbol(&numbers)
+        .iter()
+        .filter(|(p, nums)| grid[p.y as usize][p.x as usize] == '*' && nums.len() == 2)
+        .map(|(_, nums)| nums[0] * nums[1])
+        .sum();
+    assert_eq!(gear_sum, 467835);
+
+    // Past i64::MAX but still a u64: kept as-is rather than wrapping negative.
+    let wide = parse_char_grid("9999999999999999999*");
+    assert_eq!(
+        numbers_adjacent_to(&wide, |c| c == '*'),
+        vec![(9_999_999_999_999_999_999, vec![Point::new(19, 0)])]
+    );
+}
+
+#[test]
+fn fold_matches_origami_example() {
+    let dots: std::collections::HashSet<Point> = "\
+6,10 0,14 9,10 0,3 10,4 4,11 6,0 6,12 4,1 0,13 10,12 3,4 3,0 8,4 1,10 2,14 8,10 9,0"
+        .split_whitespace()
+        .map(|pair| {
+            let (x, y) = pair.split_once(',').unwrap();
+            Point::new(x.parse().unwrap(), y.parse().unwrap())
+        })
+        .collect();
+
+    let once = fold_y(&dots, 7);
+    assert_eq!(once.len(), 17);
+
+    let twice = fold_x(&once, 5);
+    assert_eq!(twice.len(), 16);
+    assert_eq!(render_points(&twice), "#####\n#...#\n#...#\n#...#\n#####");
+}
+
+fn digit_grid(text: &str) -> Vec<Vec<u64>> {
+    text.lines()
+        .map(|line| line.bytes().map(|b| u64::from(b - b'0')).collect())
+        .collect()
+}
+
+#[test]
+fn crucible_paths_match_examples() {
+    let city = digit_grid(
+        "\
+2413432311323
+3215453535623
+3255245654254
+3446585845452
+4546657867536
+1438598798454
+4457876987766
+3637877979653
+4654967986887
+4564679986453
+1224686865563
+2546548887735
+4322674655533",
+    );
+    let goal = Point::new(12, 12);
+    let start = Point::new(0, 0);
+    assert_eq!(
+        min_cost_path_constrained(&city, start, goal, 1, 3),
+        Some(102)
+    );
+    assert_eq!(
+        min_cost_path_constrained(&city, start, goal, 4, 10),
+        Some(94)
+    );
+
+    let unlucky = digit_grid(
+        "\
+111111111111
+999999999991
+999999999991
+999999999991
+999999999991",
+    );
+    let goal = Point::new(11, 4);
+    assert_eq!(
+        min_cost_path_constrained(&unlucky, start, goal, 4, 10),
+        Some(71)
+    );
+}
+
+#[test]
+fn circular_list_plays_marble_game() {
+    let (players, last_marble) = (9, 25);
+    let mut circle: CircularList<u64> = std::iter::once(0).collect();
+    let mut scores = vec![0u64; players];
+    for marble in 1..=last_marble {
+        if marble % 23 == 0 {
+            let taken = circle.remove_at(-7).unwrap();
+            scores[(marble as usize - 1) % players] += marble + taken;
+        } else {
+            circle.insert_after(1, marble);
+        }
+    }
+    assert_eq!(scores.iter().max(), Some(&32));
+    assert_eq!(circle.current(), Some(&25));
+    assert_eq!(circle.len(), 24);
+}
+
+#[test]
+fn range_tokens_reject_empty_and_reversed_ranges() {
+    assert_eq!(
+        parse_ranges_flexible("1-3, 4..=6 7..9\n10,12").unwrap(),
+        vec![(1, 3), (4, 6), (7, 8), (10, 12)]
+    );
+
+    let err = format!("{:#}", parse_ranges_flexible("5..5").unwrap_err());
+    assert!(err.contains("Empty range '5..5'"), "{err}");
+    let err = format!(
+        "{:#}",
+        parse_ranges_flexible("0..-9223372036854775808").unwrap_err()
+    );
+    assert!(err.contains("Empty range"), "{err}");
+    let err = format!("{:#}", parse_ranges_flexible("9-3").unwrap_err());
+    assert!(err.contains("'9-3'"), "{err}");
+}
+
+#[test]
+fn knot_hash_matches_known_values() {
+    let sparse = knot_rounds(5, &[3, 4, 1, 5], 1).unwrap();
+    assert_eq!(sparse, vec![3, 4, 2, 1, 0]);
+    assert_eq!(sparse[0] * sparse[1], 12);
+    assert!(knot_rounds(5, &[6], 1).is_err());
+    assert!(knot_rounds(0, &[1], 1).is_err());
+    assert_eq!(knot_rounds(0, &[0], 3).unwrap(), Vec::<usize>::new());
+
+    assert_eq!(knot_hash(""), "a2582a3a0e66e6e86e3812dcb672a272");
+    assert_eq!(knot_hash("AoC 2017"), "33efeb34ea91902bb2f59c9920caa6cd");
+}
+
+fn wire(path: &str) -> Vec<Point> {
+    let mut at = Point::new(0, 0);
+    let mut corners = vec![at];
+    for step in path.split(',') {
+        let n: i64 = step[1..].parse().unwrap();
+        at = match &step[..1] {
+            "R" => Point::new(at.x + n, at.y),
+            "L" => Point::new(at.x - n, at.y),
+            "U" => Point::new(at.x, at.y - n),
+            _ => Point::new(at.x, at.y + n),
+        };
+        corners.push(at);
+    }
+    corners
+}
+
+#[test]
+fn wires_cross_nearest_origin() {
+    let origin = Point::new(0, 0);
+    let closest = |a: &str, b: &str| {
+        manhattan_closest_intersection(&wire(a), &wire(b)).map(|p| p.manhattan(origin))
+    };
+    assert_eq!(closest("R8,U5,L5,D3", "U7,R6,D4,L4"), Some(6));
+    assert_eq!(
+        closest(
+            "R75,D30,R83,U83,L12,D49,R71,U7,L72",
+            "U62,R66,U55,R34,D71,R55,D58,R83"
+        ),
+        Some(159)
+    );
+
+    // Collinear overlaps: a shared run (3,0)..(8,0), then one from the origin where (1,0) is nearest.
+    assert_eq!(
+        segment_intersection(
+            origin,
+            Point::new(10, 0),
+            Point::new(8, 0),
+            Point::new(3, 0)
+        ),
+        Some(Point::new(3, 0))
+    );
+    assert_eq!(closest("R10", "U2,R3,D2,R5"), Some(3));
+    assert_eq!(closest("R5,U3", "D1,L2,U1,R6"), Some(1));
+}
+
+#[test]
+fn tsp_matches_city_example() {
+    // London, Dublin, Belfast.
+    let dist = vec![vec![0, 464, 518], vec![464, 0, 141], vec![518, 141, 0]];
+    assert_eq!(tsp_min(&dist, false).unwrap(), 605);
+    assert_eq!(tsp_max(&dist, false).unwrap(), 982);
+
+    assert!(tsp_min(&vec![vec![1; 17]; 17], false).is_err());
+    assert!(tsp_min(&[vec![0, 1], vec![1]], true).is_err());
+}
+
+#[test]
+fn rolling_hash_finds_overlaps_and_repeats() {
+    assert_eq!(RollingHash::find_all(b"aaaa", b"aa"), vec![0, 1, 2]);
+    assert_eq!(RollingHash::find(b"banana", b"nan"), Some(2));
+    assert_eq!(longest_repeated_substring(b"banana"), b"ana");
+    assert_eq!(longest_repeated_substring(b"abc"), b"");
+}
+
+#[test]
+fn permutations_and_combinations_count_and_edges() {
+    let perms: std::collections::HashSet<Vec<u8>> = permutations(&[1, 2, 3, 4]).collect();
+    assert_eq!(perms.len(), 24);
+    assert_eq!(permutations(&[1, 2, 3]).count(), 6);
+
+    let pairs: Vec<Vec<u8>> = combinations(&[1, 2, 3, 4, 5], 2).collect();
+    assert_eq!(pairs.len(), 10);
+    assert_eq!(pairs[0], vec![1, 2]);
+    assert_eq!(pairs[9], vec![4, 5]);
+
+    let empty: Vec<Vec<u8>> = combinations(&[1, 2, 3], 0).collect();
+    assert_eq!(empty, vec![Vec::<u8>::new()]);
+    assert_eq!(combinations(&[1, 2, 3], 4).count(), 0);
+    assert_eq!(combinations(&[1, 2, 3], 3).count(), 1);
+}
+
+#[test]
+fn hex_path_splits_and_rejects_unknown_letters() {
+    assert_eq!(parse_hex_path("esenee\n").unwrap(), ["e", "se", "ne", "e"]);
+    assert_eq!(parse_hex_path("ne, ne,s").unwrap(), ["ne", "ne", "s"]);
+    assert!(parse_hex_path("neé").is_err());
+    assert!(parse_hex_path("nex").is_err());
+    assert!(parse_hex_path("ne,nee,s").is_err());
+    assert!(parse_hex_path("ne, x").is_err());
+}
+
+#[test]
+fn vm_reports_overflow_instead_of_panicking() {
+    let program = aoc2025::vm::parse_program("cpy 9223372036854775807 a\ninc a").unwrap();
+    let mut machine = aoc2025::vm::Machine::new(program);
+    let err = machine.run().unwrap_err();
+    assert!(err.to_string().contains("overflow in 'inc'"), "{err}");
+
+    let program = aoc2025::vm::parse_program("mul a 2\njnz 1 9223372036854775807").unwrap();
+    let mut machine = aoc2025::vm::Machine::new(program);
+    assert_eq!(machine.run().unwrap(), aoc2025::vm::Status::Halted);
+}
+
+#[test]
+fn tape_reports_overflow_instead_of_panicking() {
+    use aoc2025::tape::{parse_tape, TapeMachine};
+
+    let mut machine = TapeMachine::intcode(parse_tape("1,0,0,0,99").unwrap());
+    machine.run_until_input_or_halt().unwrap();
+    assert_eq!(machine.read(0), 2);
+
+    let mut machine = TapeMachine::intcode(parse_tape("1102,9223372036854775807,2,0,99").unwrap());
+    let err = machine.run_until_input_or_halt().unwrap_err();
+    assert!(err.to_string().contains("overflow"), "{err}");
+}
+
+#[test]
+fn reachable_within_keeps_step_parity() {
+    // Open plane: exactly `s` steps reach the (s + 1)^2 cells of matching parity.
+    for steps in [0, 1, 2, 3, 6] {
+        assert_eq!(
+            reachable_within(Point::new(0, 0), steps, |_| true),
+            (steps + 1).pow(2)
+        );
+    }
+
+    let garden = parse_char_grid(
+        "\
+...........
+.....###.#.
+.###.##..#.
+..#.#...#..
+....#.#....
+.##..S####.
+.##..#...#.
+.......##..
+.##.#.####.
+.##..##.##.
+...........",
+    );
+    let open = |p: Point| {
+        let row = usize::try_from(p.y).ok().and_then(|y| garden.get(y));
+        let cell = row.and_then(|r| usize::try_from(p.x).ok().and_then(|x| r.get(x)));
+        cell.map_or(false, |&c| c != '#')
+    };
+    let start = Point::new(5, 5);
+    assert_eq!(reachable_within(start, 1, open), 2);
+    assert_eq!(reachable_within(start, 2, open), 4);
+    assert_eq!(reachable_within(start, 3, open), 6);
+    assert_eq!(reachable_within(start, 6, open), 16);
+}
+
+#[cfg(feature = "hashing")]
+#[test]
+fn hash_prefix_search_rejects_impossible_prefixes() {
+    use aoc2025::hashing::{find_hash_with_prefix, has_zero_prefix};
+
+    assert_eq!(find_hash_with_prefix("abcdef", 5).unwrap(), 609043);
+    assert!(has_zero_prefix(&[0; 16], 32));
+    assert!(!has_zero_prefix(&[0; 16], 33));
+    assert!(find_hash_with_prefix("abcdef", 33).is_err());
+}
+
+#[test]
+fn grid_set_matches_hash_set_oracle() {
+    // 13 x 7 = 91 cells, so the bitset spans two words.
+    let (width, height) = (13, 7);
+    let mut set = GridSet::new(width, height);
+    let mut oracle = std::collections::HashSet::new();
+    let mut state = 12345u64;
+    for _ in 0..2000 {
+        state = state
+            .wrapping_mul(6364136223846793005)
+            .wrapping_add(1442695040888963407);
+        let r = state >> 33;
+        let pt = Point::new((r % width as u64) as i64, (r / 16 % height as u64) as i64);
+        if r % 3 == 0 {
+            assert_eq!(set.remove(pt), oracle.remove(&pt), "remove {pt}");
+        } else {
+            assert_eq!(set.insert(pt), oracle.insert(pt), "insert {pt}");
+        }
+        assert_eq!(set.len(), oracle.len());
+    }
+    for y in -1..=height as i64 {
+        for x in -1..=width as i64 {
+            let pt = Point::new(x, y);
+            assert_eq!(set.contains(pt), oracle.contains(&pt), "contains {pt}");
+        }
+    }
+    let mut members: Vec<Point> = oracle.into_iter().collect();
+    members.sort_unstable_by_key(|p| (p.y, p.x));
+    assert_eq!(set.iter().collect::<Vec<_>>(), members);
+}
+
+#[test]
+fn grid_searches_handle_ragged_rows() {
+    // Row 1 is a single cell, so (1,1) and (2,1) don't exist: the path hugs column 0.
+    let bytes: Vec<Vec<u8>> = vec![vec![1, 1, 1], vec![1], vec![1, 1, 1]];
+    let (cost, path) = astar_grid(&bytes, Point::new(0, 0), Point::new(2, 2)).unwrap();
+    assert_eq!(cost, 4);
+    assert_eq!(path.len(), 5);
+    assert_eq!(astar_grid(&bytes, Point::new(0, 0), Point::new(2, 1)), None);
+
+    let costs: Vec<Vec<u64>> = vec![vec![1, 1, 1], vec![1], vec![1, 1, 1]];
+    assert_eq!(
+        dijkstra_grid(&costs, Point::new(0, 0), Point::new(2, 2)),
+        Some(4)
+    );
+    assert_eq!(
+        dijkstra_grid(&costs, Point::new(0, 0), Point::new(1, 1)),
+        None
+    );
+    let (start, goal) = (Point::new(0, 0), Point::new(2, 2));
+    assert_eq!(
+        min_cost_path_constrained(&costs, start, goal, 1, 3),
+        Some(4)
+    );
+}
+
+#[test]
+fn safe_sum_reports_overflow_instead_of_panicking() {
+    let fits: SafeSum = [i64::MAX, 1, -2].into_iter().collect();
+    assert_eq!(fits.to_i64().unwrap(), i64::MAX - 1);
+
+    let wide: SafeSum = [i64::MAX, i64::MAX].into_iter().collect();
+    assert_eq!(wide.total(), Some(2 * i64::MAX as i128));
+    assert!(wide.to_i64().is_err());
+
+    let mut huge = SafeSum::new();
+    huge.add(i128::MAX);
+    huge.add(1);
+    huge.add(-1);
+    assert_eq!(huge.total(), None);
+    assert!(huge
+        .to_i64()
+        .unwrap_err()
+        .to_string()
+        .contains("overflowed"));
+}
+
+#[test]
+fn circular_list_remove_after_mirrors_insert_after() {
+    let mut ring: CircularList<u32> = (0..4).collect();
+    assert_eq!(ring.remove_after(0), Some(1));
+    assert_eq!(ring.iter().copied().collect::<Vec<_>>(), [2, 3, 0]);
+
+    ring.insert_after(0, 9);
+    assert_eq!(ring.iter().copied().collect::<Vec<_>>(), [9, 3, 0, 2]);
+    // The inserted value sits right after its anchor, one step back from the cursor.
+    assert_eq!(ring.remove_after(-1), Some(9));
+    assert_eq!(ring.iter().copied().collect::<Vec<_>>(), [3, 0, 2]);
+
+    assert_eq!(ring.remove_after(2), Some(3));
+    assert_eq!(ring.current(), Some(&0));
+    assert_eq!(CircularList::<u32>::new().remove_after(0), None);
+}
+
+#[test]
+fn config_keeps_hashes_inside_values() {
+    let config = parse_config(
+        "\
+# comment line
+session = 53616c7465645f5f#abc   # trailing comment
+user_agent = \"me (#aoc) <a@b.c>\" # quoted
+year = 2024
+",
+    );
+    assert_eq!(config.session.as_deref(), Some("53616c7465645f5f#abc"));
+    assert_eq!(config.user_agent.as_deref(), Some("me (#aoc) <a@b.c>"));
+    assert_eq!(config.year, Some(2024));
+
+    let commented = parse_config("  # session = nope\nsession = # unset");
+    assert_eq!(commented, Config::default());
+}
+
+#[test]
+fn binary_search_first_true_boundaries() {
+    assert_eq!(binary_search_first_true(0, 100, |x| x * x >= 50), Some(8));
+    assert_eq!(binary_search_first_true(-100, 100, |x| x >= -7), Some(-7));
+    assert_eq!(binary_search_first_true(3, 9, |_| true), Some(3));
+    assert_eq!(binary_search_first_true(5, 5, |x| x == 5), Some(5));
+    assert_eq!(binary_search_first_true(0, 100, |x| x > 100), None);
+    assert_eq!(binary_search_first_true(10, 3, |_| true), None);
+    assert_eq!(
+        binary_search_first_true(i64::MIN, i64::MAX, |x| x >= 0),
+        Some(0)
+    );
+    assert_eq!(
+        binary_search_first_true(i64::MIN, i64::MAX, |_| true),
+        Some(i64::MIN)
+    );
+}
+
+#[test]
+fn run_n_steps_matches_brute_force_and_skips_cycles() {
+    // Prefix 0..5, then the cycle 5 -> 6 -> 7 -> 5.
+    let step = |x: &u32| if *x < 5 { x + 1 } else { 5 + (x - 4) % 3 };
+    for n in 0..50 {
+        let mut expected = 0;
+        for _ in 0..n {
+            expected = step(&expected);
+        }
+        assert_eq!(run_n_steps(0, step, n), expected, "n = {n}");
+    }
+
+    let mut calls = 0;
+    let counted = |x: &u32| {
+        calls += 1;
+        step(x)
+    };
+    let n = 1_000_000_000;
+    assert_eq!(run_n_steps(0, counted, n), 5 + ((n - 5) % 3) as u32);
+    assert!(calls < 20, "stepped {calls} times");
+}
+
+#[test]
+fn closest_and_farthest_points() {
+    let points = [
+        Point::new(2, 0),
+        Point::new(0, 2),
+        Point::new(-3, 1),
+        Point::new(1, -3),
+    ];
+    let origin = Point::new(0, 0);
+    assert_eq!(closest_to(&points, origin), Some(Point::new(2, 0)));
+    assert_eq!(farthest_from(&points, origin), Some(Point::new(-3, 1)));
+    assert_eq!(closest_to(&[], origin), None);
+    assert_eq!(farthest_from(&[], origin), None);
+
+    assert_eq!(pairwise_max_distance(&points), 8);
+    assert_eq!(pairwise_max_distance(&points[..1]), 0);
+    assert_eq!(pairwise_max_distance(&[]), 0);
+}
+
+#[test]
+fn parse_int_grid_rect_reports_dimensions_and_ragged_rows() {
+    let (grid, width, height) = parse_int_grid_rect("1 2 3\n4 -5 6\n").unwrap();
+    assert_eq!(grid, vec![vec![1, 2, 3], vec![4, -5, 6]]);
+    assert_eq!((width, height), (3, 2));
+
+    let err = parse_int_grid_rect("1 2 3\n4 5\n6 7 8")
+        .unwrap_err()
+        .to_string();
+    assert!(err.contains("line 2"), "{err}");
+    assert!(err.contains("'4 5'"), "{err}");
+
+    assert_eq!(parse_int_grid_rect("").unwrap(), (vec![], 0, 0));
+}
+
+#[test]
+fn connected_components_and_find_all_are_row_major() {
+    let chars = parse_char_grid("aab\nabb\nccb");
+    let p = Point::new;
+    assert_eq!(
+        connected_components(&chars),
+        vec![
+            vec![p(0, 0), p(1, 0), p(0, 1)],
+            vec![p(2, 0), p(1, 1), p(2, 1), p(2, 2)],
+            vec![p(0, 2), p(1, 2)],
+        ]
+    );
+    assert!(connected_components::<char>(&[]).is_empty());
+
+    let grid = Grid::from_rows(chars).unwrap();
+    assert_eq!(
+        grid.find_all(|&c| c == 'b'),
+        vec![p(2, 0), p(1, 1), p(2, 1), p(2, 2)]
+    );
+    assert!(grid.find_all(|&c| c == 'z').is_empty());
+}
+
+#[test]
+fn parse_lines_collect_reports_every_bad_line() {
+    let (values, errors) = parse_lines_collect::<i64>("1\nx\n\n  3  \n4.5\n-2\n");
+    assert_eq!(values, vec![1, 3, -2]);
+    assert_eq!(errors, vec![(2, "x".to_string()), (5, "4.5".to_string())]);
+
+    let (values, errors) = parse_lines_collect::<u8>("");
+    assert!(values.is_empty() && errors.is_empty());
+}
+
+#[test]
+fn top_n_edge_cases() {
+    assert_eq!(top_n(vec![3, 1, 4, 1, 5, 9, 2, 6], 3), vec![9, 6, 5]);
+    assert_eq!(top_n(vec![2, 7, 7, 1, 7], 2), vec![7, 7]);
+    assert_eq!(top_n(vec![2, 3, 1], 10), vec![3, 2, 1]);
+    assert!(top_n(vec![1, 2, 3], 0).is_empty());
+    assert!(top_n(Vec::<i32>::new(), 3).is_empty());
+
+    let calories = [6000, 4000, 11000, 24000, 10000];
+    assert_eq!(sum_top_n(calories, 1), 24000);
+    assert_eq!(sum_top_n(calories, 3), 45000);
+    assert_eq!(sum_top_n(calories, 0), 0);
+}